@@ -1,5 +1,5 @@
 use beeper_desktop_api::{BeeperDesktop, Config};
-use beeper_desktop_api::resources::{MessageSendParams, ChatSearchParams};
+use beeper_desktop_api::resources::{MessageSendParams, SendOutcome, ChatSearchParams};
 use std::time::Duration;
 
 #[tokio::main]
@@ -55,12 +55,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         };
         
         match client.messages().send(&send_params).await {
-            Ok(response) => {
-                if response.success {
-                    println!("Message sent successfully: {}", response.message_id);
-                } else {
-                    println!("Failed to send message: {:?}", response.error);
-                }
+            Ok(SendOutcome::Sent { message_id, .. }) => {
+                println!("Message sent successfully: {}", message_id);
+            }
+            Ok(SendOutcome::Rejected { reason }) => {
+                println!("Failed to send message: {:?}", reason);
             }
             Err(e) => {
                 println!("Error sending message: {}", e);
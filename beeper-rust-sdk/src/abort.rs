@@ -0,0 +1,102 @@
+//! Cooperative cancellation for in-flight requests
+//!
+//! [`AbortSignal`] is a cheaply-cloneable handle that callers can hold onto and
+//! trip from outside the request that's using it — useful for interactive UIs
+//! and bots that supersede a pending `search` or `send` with a newer one. It is
+//! deliberately *cooperative*: tripping it doesn't kill the underlying HTTP
+//! future, it just wins the `tokio::select!` race the next time the signal is
+//! polled, so the request stops waiting on the response rather than being torn
+//! down mid-flight.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::Notify;
+
+/// A cheaply-cloneable cancellation handle. Every clone observes the same
+/// underlying state, so tripping any clone with [`AbortSignal::abort`] cancels
+/// every in-flight request holding one.
+#[derive(Debug, Clone)]
+pub struct AbortSignal {
+    aborted: Arc<AtomicBool>,
+    notify: Arc<Notify>,
+}
+
+impl AbortSignal {
+    /// Create a new, untripped signal
+    pub fn new() -> Self {
+        Self {
+            aborted: Arc::new(AtomicBool::new(false)),
+            notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Trip the signal, cancelling every request racing on [`AbortSignal::cancelled`]
+    pub fn abort(&self) {
+        self.aborted.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    /// Whether the signal has been tripped
+    pub fn is_aborted(&self) -> bool {
+        self.aborted.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once the signal is tripped; used with `tokio::select!` to race
+    /// against an in-flight request future. Resolves immediately if the signal
+    /// was already tripped before this call.
+    pub async fn cancelled(&self) {
+        if self.is_aborted() {
+            return;
+        }
+        loop {
+            let notified = self.notify.notified();
+            if self.is_aborted() {
+                return;
+            }
+            notified.await;
+            if self.is_aborted() {
+                return;
+            }
+        }
+    }
+}
+
+impl Default for AbortSignal {
+    /// A signal that never trips, for call sites that don't need cancellation
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancelled_resolves_after_abort() {
+        let signal = AbortSignal::new();
+        let waiter = signal.clone();
+
+        let handle = tokio::spawn(async move {
+            waiter.cancelled().await;
+        });
+
+        assert!(!signal.is_aborted());
+        signal.abort();
+
+        tokio::time::timeout(std::time::Duration::from_secs(1), handle)
+            .await
+            .expect("cancelled() should resolve once abort() is called")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_resolves_immediately_if_already_aborted() {
+        let signal = AbortSignal::new();
+        signal.abort();
+
+        tokio::time::timeout(std::time::Duration::from_millis(100), signal.cancelled())
+            .await
+            .expect("cancelled() must not block when already aborted");
+    }
+}
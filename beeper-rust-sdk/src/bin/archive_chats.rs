@@ -1,11 +1,75 @@
 use beeper_desktop_api::{BeeperDesktop, Config};
+use beeper_desktop_api::export::{Exporter, HtmlExporter, JsonExporter, MarkdownExporter};
 use beeper_desktop_api::resources::{ChatSearchParams, MessageSearchParams};
-use chrono::Utc;
-use std::fs::{create_dir_all, File};
+use std::fs::create_dir_all;
 use std::io::{self, Write};
 use std::path::Path;
 use std::time::Duration;
 
+/// Export format selected via `--format`, defaulting to [`ExportFormat::Markdown`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportFormat {
+    Markdown,
+    Json,
+    Html,
+}
+
+impl ExportFormat {
+    fn parse(value: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        match value.to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "json" => Ok(Self::Json),
+            "html" => Ok(Self::Html),
+            other => Err(format!("unsupported --format {other:?} (expected markdown, json, or html)").into()),
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Json => "json",
+            Self::Html => "html",
+        }
+    }
+
+    fn new_exporter(self) -> Box<dyn Exporter> {
+        match self {
+            Self::Markdown => Box::new(MarkdownExporter::new()),
+            Self::Json => Box::new(JsonExporter::new()),
+            Self::Html => Box::new(HtmlExporter::new()),
+        }
+    }
+}
+
+/// CLI options parsed from `std::env::args`
+struct Cli {
+    format: ExportFormat,
+    encrypt: bool,
+}
+
+impl Cli {
+    fn parse() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut format = ExportFormat::Markdown;
+        let mut encrypt = false;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--format" => {
+                    let value = args
+                        .next()
+                        .ok_or("--format requires a value (markdown, json, or html)")?;
+                    format = ExportFormat::parse(&value)?;
+                }
+                "--encrypt" => encrypt = true,
+                other => return Err(format!("unrecognized argument {other:?}").into()),
+            }
+        }
+
+        Ok(Self { format, encrypt })
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Initialize logging
@@ -17,6 +81,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  Beeper Chat Archive Tool");
     println!("============================\n");
 
+    let cli = Cli::parse()?;
+
+    // `EncryptingExporter` needs a `Cipher`, but this crate intentionally
+    // ships no concrete implementation (see `export::Cipher`'s doc comment) —
+    // it's an integration point, not a usable cipher. Wiring `--encrypt` up
+    // for real requires a caller-supplied `Cipher`, which this binary doesn't
+    // have one of, so fail clearly instead of pretending to encrypt.
+    if cli.encrypt {
+        eprintln!(
+            "✗ --encrypt requires a Cipher implementation, which this crate doesn't ship \
+             (see beeper_desktop_api::export::Cipher); wrap the exporter in \
+             EncryptingExporter with your own Cipher instead of using this flag."
+        );
+        return Ok(());
+    }
+
     // Create client
     let client = match create_client().await {
         Ok(client) => {
@@ -76,7 +156,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     for (i, chat) in selected_chats.iter().enumerate() {
         println!("\n[{}/{}] Archiving: {}", i + 1, selected_chats.len(), chat.title);
         
-        match archive_chat(&client, chat, output_dir).await {
+        match archive_chat(&client, chat, output_dir, cli.format).await {
             Ok(message_count) => {
                 println!("  ✓ Archived {} messages", message_count);
             }
@@ -177,34 +257,14 @@ async fn archive_chat(
     client: &BeeperDesktop,
     chat: &beeper_desktop_api::Chat,
     output_dir: &str,
+    format: ExportFormat,
 ) -> Result<usize, Box<dyn std::error::Error>> {
     // Sanitize filename
-    let filename = sanitize_filename(&format!("{}_{}.md", chat.network, chat.title));
+    let filename = sanitize_filename(&format!("{}_{}.{}", chat.network, chat.title, format.extension()));
     let filepath = Path::new(output_dir).join(filename);
 
-    // Create markdown file
-    let mut file = File::create(&filepath)?;
-
-    // Write header
-    writeln!(file, "# Chat Archive: {}\n", chat.title)?;
-    writeln!(file, "- **Network:** {}", chat.network)?;
-    writeln!(file, "- **Chat ID:** {}", chat.id)?;
-    writeln!(file, "- **Type:** {}", chat.chat_type)?;
-    writeln!(file, "- **Participants:** {}", chat.participants.total)?;
-    writeln!(file, "- **Archived on:** {}\n", Utc::now().format("%Y-%m-%d %H:%M:%S UTC"))?;
-
-    if !chat.participants.items.is_empty() {
-        writeln!(file, "## Participants\n")?;
-        for participant in &chat.participants.items {
-            writeln!(file, "- **{}** ({})", 
-                participant.full_name.as_deref().unwrap_or("Unknown"),
-                participant.id
-            )?;
-        }
-        writeln!(file)?;
-    }
-
-    writeln!(file, "## Messages\n")?;
+    let mut exporter = format.new_exporter();
+    exporter.begin_chat(chat)?;
 
     // Fetch all messages for this chat
     let mut params = MessageSearchParams::new();
@@ -212,7 +272,7 @@ async fn archive_chat(
     params.limit = Some(100);
 
     let mut message_count = 0;
-    
+
     loop {
         match client.messages().search(&params).await {
             Ok(messages) => {
@@ -221,7 +281,7 @@ async fn archive_chat(
                 sorted_messages.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
 
                 for message in &sorted_messages {
-                    write_message_to_file(&mut file, message)?;
+                    exporter.write_message(message)?;
                     message_count += 1;
                 }
 
@@ -241,59 +301,13 @@ async fn archive_chat(
         }
     }
 
-    writeln!(file, "\n---\n*Archive generated by Beeper Chat Archive Tool*")?;
+    let archive = exporter.finish_chat()?;
+    std::fs::write(&filepath, &archive)?;
 
     println!("  ✓ Saved to: {}", filepath.display());
     Ok(message_count)
 }
 
-fn write_message_to_file(
-    file: &mut File,
-    message: &beeper_desktop_api::Message,
-) -> Result<(), Box<dyn std::error::Error>> {
-    let timestamp = message.timestamp.format("%Y-%m-%d %H:%M:%S");
-    let sender = message.sender_name.as_deref().unwrap_or(&message.sender_id);
-    
-    writeln!(file, "### {} - {}", sender, timestamp)?;
-    
-    if let Some(text) = &message.text {
-        writeln!(file, "{}\n", text)?;
-    }
-    
-    // Handle attachments
-    if let Some(attachments) = &message.attachments {
-        if !attachments.is_empty() {
-            writeln!(file, "**Attachments:**")?;
-            for attachment in attachments {
-                let file_name = attachment.file_name.as_deref().unwrap_or("Unknown");
-                let file_type = &attachment.attachment_type;
-                writeln!(file, "- {} ({})", file_name, file_type)?;
-                
-                if let Some(src_url) = &attachment.src_url {
-                    writeln!(file, "  - URL: {}", src_url)?;
-                }
-            }
-            writeln!(file)?;
-        }
-    }
-    
-    // Handle reactions
-    if let Some(reactions) = &message.reactions {
-        if !reactions.is_empty() {
-            write!(file, "**Reactions:** ")?;
-            for (i, reaction) in reactions.iter().enumerate() {
-                if i > 0 {
-                    write!(file, ", ")?;
-                }
-                write!(file, "{}", reaction.reaction_key)?;
-            }
-            writeln!(file, "\n")?;
-        }
-    }
-
-    Ok(())
-}
-
 fn sanitize_filename(name: &str) -> String {
     name.chars()
         .map(|c| {
@@ -1,7 +1,7 @@
 use beeper_desktop_api::{BeeperDesktop, Config, Error};
 use beeper_desktop_api::resources::{
-    MessageSearchParams, MessageSendParams, ChatSearchParams, ChatCreateParams,
-    ContactSearchParams, AppSearchParams,
+    MessageSearchParams, MessageSendParams, SendOutcome, ChatSearchParams, ChatCreateParams,
+    CreateOutcome, ContactSearchParams, AppSearchParams,
 };
 use std::io::{self, Write};
 use std::time::Duration;
@@ -342,14 +342,13 @@ async fn test_send_message(client: &BeeperDesktop) {
     };
     
     match client.messages().send(&params).await {
-        Ok(response) => {
-            if response.success {
-                println!("✓ Message sent successfully!");
-                println!("  Message ID: {}", response.message_id);
-                println!("  Deeplink: {}", response.deeplink);
-            } else {
-                println!("✗ Failed to send message: {:?}", response.error);
-            }
+        Ok(SendOutcome::Sent { message_id, deeplink }) => {
+            println!("✓ Message sent successfully!");
+            println!("  Message ID: {}", message_id);
+            println!("  Deeplink: {}", deeplink);
+        }
+        Ok(SendOutcome::Rejected { reason }) => {
+            println!("✗ Failed to send message: {:?}", reason);
         }
         Err(e) => println!("✗ Error: {}", e),
     }
@@ -392,19 +391,18 @@ async fn test_create_chat(client: &BeeperDesktop) {
     let params = ChatCreateParams {
         account_id: selected_account.account_id.clone(),
         participant_ids: vec![participant_id.trim().to_string()],
-        chat_type: chat_type.trim().to_string(),
+        chat_type: chat_type.trim().into(),
         title: None,
     };
     
     match client.chats().create(&params).await {
-        Ok(response) => {
-            if response.success {
-                println!("✓ Chat created successfully!");
-                println!("  Chat ID: {}", response.chat.id);
-                println!("  Chat Title: {}", response.chat.title);
-            } else {
-                println!("✗ Failed to create chat: {:?}", response.error);
-            }
+        Ok(CreateOutcome::Created(chat)) => {
+            println!("✓ Chat created successfully!");
+            println!("  Chat ID: {}", chat.id);
+            println!("  Chat Title: {}", chat.title);
+        }
+        Ok(CreateOutcome::Rejected { reason }) => {
+            println!("✗ Failed to create chat: {:?}", reason);
         }
         Err(e) => println!("✗ Error: {}", e),
     }
@@ -505,12 +503,11 @@ async fn test_error_handling(client: &BeeperDesktop) {
     };
     
     match client.messages().send(&params).await {
-        Ok(response) => {
-            if !response.success {
-                println!("✓ API returned error as expected: {:?}", response.error);
-            } else {
-                println!("? Unexpected success");
-            }
+        Ok(SendOutcome::Rejected { reason }) => {
+            println!("✓ API returned error as expected: {:?}", reason);
+        }
+        Ok(SendOutcome::Sent { .. }) => {
+            println!("? Unexpected success");
         }
         Err(Error::NotFound { message, .. }) => {
             println!("✓ Got expected NotFound error: {}", message);
@@ -0,0 +1,210 @@
+//! Scope-aware request preflight
+//!
+//! `UserInfo.scope` reports the space-separated OAuth scopes granted to the
+//! current token, but nothing in the SDK acted on it: a call like
+//! `Chats::create` with a read-only token just failed at the server with an
+//! opaque 403. `Capabilities` fetches and caches the granted scope set (once,
+//! on first use) so resource methods can check locally before making the
+//! request, returning a typed [`crate::Error::InsufficientScope`] instead of
+//! round-tripping to the server.
+
+use crate::client::BeeperDesktop;
+use crate::error::{Error, Result};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// A single OAuth scope, as reported in `UserInfo.scope` or required by a
+/// resource method. Scope strings are defined by the Beeper Desktop API, not
+/// this SDK; the `Scope::CHATS_WRITE`-style constants below are this SDK's
+/// best-effort naming for the scopes of its write operations. Of these, only
+/// `CHATS_WRITE` is checked by an SDK method ([`crate::resources::chats::Chats::create`]).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Scope(String);
+
+impl Scope {
+    /// Required by [`crate::resources::chats::Chats::create`] and other
+    /// chat-mutating methods
+    pub const CHATS_WRITE: &'static str = "chats.write";
+
+    /// The scope for message-sending/editing/reacting. No method of this SDK
+    /// preflights it; check it yourself with `Capabilities::has`/`require`
+    pub const MESSAGES_WRITE: &'static str = "messages.write";
+
+    /// Create a scope from an arbitrary name, for scopes this SDK doesn't name
+    /// as a constant
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Scope {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Capabilities checks the current token's granted scopes, caching them on the
+/// [`BeeperDesktop`] client after the first `/oauth/userinfo` fetch
+#[derive(Debug, Clone)]
+pub struct Capabilities {
+    client: BeeperDesktop,
+}
+
+impl Capabilities {
+    pub(crate) fn new(client: BeeperDesktop) -> Self {
+        Self { client }
+    }
+
+    /// The granted scope set, fetching and caching it on first use
+    async fn granted(&self) -> Result<Arc<HashSet<Scope>>> {
+        self.client
+            .scope_cache()
+            .get_or_try_init(|| async {
+                let info = self.client.token().info().await?;
+                let scopes = info.scope.split_whitespace().map(Scope::new).collect();
+                Ok(Arc::new(scopes))
+            })
+            .await
+            .cloned()
+    }
+
+    /// Whether the current token has been granted `scope`
+    pub async fn has(&self, scope: &Scope) -> Result<bool> {
+        Ok(self.granted().await?.contains(scope))
+    }
+
+    /// Returns `Ok(())` if every scope in `scopes` is granted, otherwise
+    /// [`Error::InsufficientScope`] naming what was required vs. granted
+    pub async fn require(&self, scopes: &[Scope]) -> Result<()> {
+        let granted = self.granted().await?;
+        let missing: Vec<Scope> = scopes.iter().filter(|s| !granted.contains(s)).cloned().collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InsufficientScope {
+                required: missing,
+                granted: granted.iter().cloned().collect(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BeeperDesktop, Config};
+    use serde_json::json;
+    use std::time::Duration;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn setup_mock_server() -> (MockServer, BeeperDesktop) {
+        let mock_server = MockServer::start().await;
+
+        let config = Config::builder()
+            .access_token("test-token")
+            .base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        (mock_server, client)
+    }
+
+    #[tokio::test]
+    async fn test_has_reflects_granted_scopes() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "iat": 0,
+                "scope": "chats.write contacts.read",
+                "sub": "token-1",
+                "token_use": "access",
+                "aud": null,
+                "client_id": null,
+                "exp": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let caps = client.capabilities();
+        assert!(caps.has(&Scope::new(Scope::CHATS_WRITE)).await.unwrap());
+        assert!(!caps.has(&Scope::new(Scope::MESSAGES_WRITE)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_require_fails_with_missing_scopes_listed() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "iat": 0,
+                "scope": "contacts.read",
+                "sub": "token-1",
+                "token_use": "access",
+                "aud": null,
+                "client_id": null,
+                "exp": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let err = client
+            .capabilities()
+            .require(&[Scope::new(Scope::CHATS_WRITE)])
+            .await
+            .unwrap_err();
+
+        match err {
+            Error::InsufficientScope { required, .. } => {
+                assert_eq!(required, vec![Scope::new(Scope::CHATS_WRITE)]);
+            }
+            other => panic!("expected InsufficientScope, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_granted_scopes_are_cached_across_calls() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "iat": 0,
+                "scope": "chats.write",
+                "sub": "token-1",
+                "token_use": "access",
+                "aud": null,
+                "client_id": null,
+                "exp": null
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        let caps = client.capabilities();
+        assert!(caps.has(&Scope::new(Scope::CHATS_WRITE)).await.unwrap());
+        // Second call must not hit the mock again (it's only registered `up_to_n_times(1)`);
+        // if the cache didn't work this would return a 404 and the unwrap would panic.
+        assert!(caps.has(&Scope::new(Scope::CHATS_WRITE)).await.unwrap());
+    }
+}
@@ -1,14 +1,36 @@
+use crate::abort::AbortSignal;
+use crate::capabilities::{Capabilities, Scope};
 use crate::config::Config;
 use crate::error::{Error, Result};
-use crate::resources::{Accounts, App, Chats, Contacts, Messages, Token};
-use reqwest::{Client as HttpClient, Method, Response, StatusCode};
+use crate::gateway::Gateway;
+use crate::resources::{Accounts, App, Chats, Contacts, Media, Messages, Token};
+use crate::resources::token::{RefreshRequest, RefreshResponse};
+use crate::transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::{Client as HttpClient, Method, StatusCode};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::{OnceCell, RwLock};
 use tracing::{debug, warn};
 use url::Url;
 
+/// Path of the OAuth token endpoint, exempted from the pre-emptive and
+/// reactive auto-refresh logic in [`BeeperDesktop::do_request_with_signal`]
+/// so a refresh attempt can never recursively trigger another refresh
+const OAUTH_TOKEN_PATH: &str = "/oauth/token";
+
+/// Generate a short, opaque correlation id for a logical request (shared across
+/// all of its retry attempts), sent to the server as `X-Request-Id` and
+/// recorded on the request's tracing span so client-side logs can be
+/// correlated with server-side logs for the same request
+fn generate_request_id() -> String {
+    let bytes: [u8; 8] = rand::thread_rng().gen();
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Error response from the API
 #[derive(Debug, Deserialize)]
 struct ErrorResponse {
@@ -18,11 +40,31 @@ struct ErrorResponse {
 }
 
 /// Main API client for the Beeper Desktop API
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct BeeperDesktop {
     config: Arc<Config>,
-    http_client: HttpClient,
+    transport: Arc<dyn Transport>,
     base_url: Url,
+    scope_cache: Arc<OnceCell<Arc<HashSet<Scope>>>>,
+    /// The access token currently in use, seeded from `config.access_token`
+    /// but mutable so [`BeeperDesktop::try_refresh_access_token`] can replace
+    /// it without requiring a new client
+    current_access_token: Arc<RwLock<String>>,
+    /// The refresh token currently in use, seeded from `config.refresh_token`
+    /// and rotated if the token endpoint issues a new one
+    current_refresh_token: Arc<RwLock<Option<String>>>,
+    /// Cached access token expiry, populated by [`crate::resources::token::Token::info`]
+    /// or after a refresh, consulted by [`BeeperDesktop::ensure_fresh_token`]
+    token_expiry: Arc<RwLock<Option<DateTime<Utc>>>>,
+}
+
+impl std::fmt::Debug for BeeperDesktop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BeeperDesktop")
+            .field("config", &self.config)
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
 }
 
 impl BeeperDesktop {
@@ -34,6 +76,21 @@ impl BeeperDesktop {
 
     /// Create a new BeeperDesktop client with the given configuration
     pub async fn with_config(config: Config) -> Result<Self> {
+        let http_client = if let Some(client) = config.http_client.clone() {
+            client
+        } else {
+            HttpClient::builder().timeout(config.timeout).build()?
+        };
+
+        Self::with_transport(config, ReqwestTransport::new(http_client))
+    }
+
+    /// Create a new BeeperDesktop client that executes requests through a
+    /// custom [`Transport`] instead of the default `reqwest`-backed one. Useful
+    /// for tests (an in-memory transport matching on method/path/query,
+    /// eliminating port binding) or alternate backends (e.g. a Unix-socket
+    /// transport to the desktop app).
+    pub fn with_transport(config: Config, transport: impl Transport + 'static) -> Result<Self> {
         config.validate()?;
 
         // Ensure base URL ends with /
@@ -44,19 +101,17 @@ impl BeeperDesktop {
         };
 
         let base_url = Url::parse(&base_url_str)?;
-
-        let http_client = if let Some(client) = config.http_client.clone() {
-            client
-        } else {
-            HttpClient::builder()
-                .timeout(config.timeout)
-                .build()?
-        };
+        let current_access_token = Arc::new(RwLock::new(config.access_token.clone()));
+        let current_refresh_token = Arc::new(RwLock::new(config.refresh_token.clone()));
 
         Ok(Self {
             config: Arc::new(config),
-            http_client,
+            transport: Arc::new(transport),
             base_url,
+            scope_cache: Arc::new(OnceCell::new()),
+            current_access_token,
+            current_refresh_token,
+            token_expiry: Arc::new(RwLock::new(None)),
         })
     }
 
@@ -85,37 +140,295 @@ impl BeeperDesktop {
         Messages::new(self.clone())
     }
 
+    /// Get the media resource client
+    pub fn media(&self) -> Media {
+        Media::new(self.clone())
+    }
+
     /// Get the token resource client
     pub fn token(&self) -> Token {
         Token::new(self.clone())
     }
 
-    /// Make an HTTP request with retry logic
+    /// Get the real-time event gateway client
+    pub fn gateway(&self) -> Gateway {
+        Gateway::new(self.clone())
+    }
+
+    /// Get the push-based event subscription client
+    pub fn events(&self) -> crate::events::Events {
+        crate::events::Events::new(self.clone())
+    }
+
+    /// Get the scope-preflight capabilities client
+    pub fn capabilities(&self) -> Capabilities {
+        Capabilities::new(self.clone())
+    }
+
+    /// The cache backing [`Capabilities`]'s granted-scope lookup, shared across
+    /// every `BeeperDesktop` clone so the `/oauth/userinfo` fetch happens once
+    pub(crate) fn scope_cache(&self) -> &OnceCell<Arc<HashSet<Scope>>> {
+        &self.scope_cache
+    }
+
+    /// Replace the access token in use, e.g. after [`crate::resources::token::Token::refresh`]
+    pub(crate) async fn set_access_token(&self, token: String) {
+        *self.current_access_token.write().await = token;
+    }
+
+    /// Replace the refresh token in use, e.g. after the token endpoint rotates it
+    pub(crate) async fn set_refresh_token(&self, token: Option<String>) {
+        *self.current_refresh_token.write().await = token;
+    }
+
+    /// Whether a refresh token is configured, consulted before the reactive
+    /// 401 handling in `do_request_with_signal`/`do_request_with_query_signal`/
+    /// `do_request_raw_with_query` attempts a refresh: without one, a refresh
+    /// attempt can only ever fail with `Error::TokenExpired`, which would
+    /// otherwise discard a fully-decoded `Error::Authentication` from the
+    /// server that has nothing to do with expiry (e.g. a revoked token)
+    async fn has_refresh_token(&self) -> bool {
+        self.current_refresh_token.read().await.is_some()
+    }
+
+    /// Record when the current access token expires, consulted by
+    /// [`BeeperDesktop::ensure_fresh_token`] to refresh ahead of expiry
+    pub(crate) async fn set_token_expiry(&self, expiry: Option<DateTime<Utc>>) {
+        *self.token_expiry.write().await = expiry;
+    }
+
+    /// Whether the cached expiry is within 30 seconds of now (or already
+    /// passed). Returns `false` if no expiry has been recorded.
+    async fn token_expiring_soon(&self) -> bool {
+        match *self.token_expiry.read().await {
+            Some(expiry) => Utc::now() >= expiry - chrono::Duration::seconds(30),
+            None => false,
+        }
+    }
+
+    /// Refresh the access token if it's expiring soon, swallowing failures
+    /// (the original request proceeds with the current token and surfaces
+    /// any resulting 401 through the normal reactive refresh-and-retry path)
+    async fn ensure_fresh_token(&self) {
+        if !self.token_expiring_soon().await {
+            return;
+        }
+
+        if let Err(error) = self.try_refresh_access_token().await {
+            warn!("Pre-emptive token refresh failed, continuing with current token: {}", error);
+        }
+    }
+
+    /// Exchange the current refresh token for a new access token, updating the
+    /// cached access token, refresh token, and expiry on success. Returns
+    /// [`Error::TokenExpired`] if no refresh token is configured.
+    ///
+    /// Goes through [`BeeperDesktop::refresh_token_raw`] rather than
+    /// [`crate::resources::token::Token::refresh`] (which calls
+    /// [`BeeperDesktop::do_request`]): `do_request` calls
+    /// `ensure_fresh_token`, which calls this method, which would call
+    /// `do_request` again — an async recursion cycle the compiler rejects
+    /// without `Box::pin`. The raw path below bypasses `do_request` entirely.
+    async fn try_refresh_access_token(&self) -> Result<()> {
+        let Some(refresh_token) = self.current_refresh_token.read().await.clone() else {
+            return Err(Error::TokenExpired {
+                message: "access token expired or was rejected and no refresh_token is configured".to_string(),
+            });
+        };
+
+        let response = self.refresh_token_raw(&RefreshRequest { refresh_token }).await?;
+
+        self.set_access_token(response.access_token).await;
+        if let Some(new_refresh_token) = response.refresh_token {
+            self.set_refresh_token(Some(new_refresh_token)).await;
+        }
+        self.set_token_expiry(
+            response
+                .expires_in
+                .map(|secs| Utc::now() + chrono::Duration::seconds(secs)),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// Exchange `req` for new credentials via a single non-retrying request
+    /// that never consults `ensure_fresh_token`, used internally by
+    /// [`BeeperDesktop::try_refresh_access_token`] to avoid the async
+    /// recursion cycle described there. Public callers use
+    /// [`crate::resources::token::Token::refresh`] instead, which gets the
+    /// usual retry behavior via [`BeeperDesktop::do_request`].
+    async fn refresh_token_raw(&self, req: &RefreshRequest) -> Result<RefreshResponse> {
+        let request_id = generate_request_id();
+        self.do_request_once(Method::POST, OAUTH_TOKEN_PATH, Some(req), &request_id).await
+    }
+
+    /// Construct the WebSocket URL for the event gateway from the configured base URL
+    pub(crate) fn gateway_url(&self) -> Result<url::Url> {
+        let mut url = self.base_url.join("v0/gateway")?;
+        let scheme = match url.scheme() {
+            "https" => "wss",
+            _ => "ws",
+        };
+        url.set_scheme(scheme)
+            .map_err(|_| Error::config("failed to derive gateway URL scheme"))?;
+        Ok(url)
+    }
+
+    /// The configured maximum number of retries, used by the gateway's reconnect loop
+    pub(crate) fn config_max_retries(&self) -> u32 {
+        self.config.max_retries
+    }
+
+    /// The access token currently in use (possibly rotated by an auto-refresh
+    /// since the client was constructed), used to authenticate the gateway
+    /// connection
+    pub(crate) async fn access_token(&self) -> String {
+        self.current_access_token.read().await.clone()
+    }
+
+    /// The configured user agent, sent alongside the access token on gateway identify
+    pub(crate) fn user_agent(&self) -> &str {
+        &self.config.user_agent
+    }
+
+    /// The configured poll interval for `Messages::watch`/`Chats::watch`
+    pub(crate) fn watch_poll_interval(&self) -> Duration {
+        self.config.watch_poll_interval
+    }
+
+    /// The capped exponential backoff with full jitter used for request retries,
+    /// reused by the gateway's reconnect loop so a flapping socket backs off the
+    /// same way a flapping HTTP request does
+    pub(crate) fn reconnect_backoff_delay(&self, attempt: u32) -> Duration {
+        self.backoff_delay(attempt)
+    }
+
+    /// Compute the backoff delay for a given (zero-indexed) retry attempt: capped
+    /// exponential backoff with full jitter, i.e. a random value in
+    /// `[0, min(max_backoff, base_delay * 2^attempt)]`.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exp = 2u64.saturating_pow(attempt);
+        let capped = self
+            .config
+            .base_delay
+            .saturating_mul(exp.min(u32::MAX as u64) as u32)
+            .min(self.config.max_backoff);
+        rand::thread_rng().gen_range(Duration::from_millis(0)..=capped.max(Duration::from_millis(1)))
+    }
+
+    /// Whether `error` should be retried, consulting `Config::retryable_status_codes`
+    /// for errors that carry an HTTP status (`Error::Api`, `Error::RateLimit` as 429)
+    /// and falling back to [`Error::is_retryable`] for the rest.
+    fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::Api { status, .. } => self.config.retryable_status_codes.contains(status),
+            Error::RateLimit { .. } => self.config.retryable_status_codes.contains(&429),
+            _ => error.is_retryable(),
+        }
+    }
+
+    /// The delay to wait before the next retry attempt: the server's `Retry-After`
+    /// if it sent one (on a 429 or 503), otherwise capped exponential backoff with
+    /// full jitter.
+    fn delay_for(&self, error: &Error, attempt: u32) -> Duration {
+        match error {
+            Error::RateLimit { retry_after: Some(delay), .. }
+            | Error::InternalServer { retry_after: Some(delay), .. } => {
+                (*delay).min(self.config.max_backoff)
+            }
+            _ => self.backoff_delay(attempt),
+        }
+    }
+
+    /// Make an HTTP request with retry logic. Delegates to
+    /// [`BeeperDesktop::do_request_with_signal`] with a signal that never trips,
+    /// so current behavior is unchanged; use that method directly to make the
+    /// request cancellable.
     pub async fn do_request<T, R>(&self, method: Method, path: &str, body: Option<&T>) -> Result<R>
     where
         T: Serialize + ?Sized,
         R: for<'de> Deserialize<'de>,
     {
+        self.do_request_with_signal(method, path, body, &AbortSignal::default()).await
+    }
+
+    /// Make an HTTP request with retry logic, cancellable via `signal`. Races
+    /// each attempt (and the delay between retries) against `signal` being
+    /// tripped; on cancellation, returns [`Error::Cancelled`] immediately instead
+    /// of starting a fresh attempt.
+    #[tracing::instrument(
+        skip(self, body, signal),
+        fields(method = %method, path = %path, request_id = tracing::field::Empty, attempt = tracing::field::Empty)
+    )]
+    pub async fn do_request_with_signal<T, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+        signal: &AbortSignal,
+    ) -> Result<R>
+    where
+        T: Serialize + ?Sized,
+        R: for<'de> Deserialize<'de>,
+    {
+        let request_id = generate_request_id();
+        tracing::Span::current().record("request_id", request_id.as_str());
+
+        if path != OAUTH_TOKEN_PATH {
+            self.ensure_fresh_token().await;
+        }
+
         let mut retries_left = self.config.max_retries;
-        
+        let mut attempt = 0u32;
+        let mut refreshed_token = false;
+
         loop {
-            match self.do_request_once(method.clone(), path, body).await {
+            tracing::Span::current().record("attempt", attempt);
+
+            let attempt_result = tokio::select! {
+                result = self.do_request_once(method.clone(), path, body, &request_id) => result,
+                _ = signal.cancelled() => return Err(Error::Cancelled),
+            };
+
+            match attempt_result {
                 Ok(result) => return Ok(result),
-                Err(error) if retries_left > 0 && error.is_retryable() => {
-                    warn!("Request failed with retryable error: {}. Retrying...", error);
-                    
-                    // Exponential backoff
-                    let delay = Duration::from_millis(1000 * (self.config.max_retries - retries_left + 1) as u64);
-                    tokio::time::sleep(delay).await;
-                    
-                    retries_left -= 1;
+                Err(error) => {
+                    self.log_notable_error(&request_id, &error);
+
+                    if path != OAUTH_TOKEN_PATH
+                        && !refreshed_token
+                        && matches!(error, Error::Authentication { .. })
+                        && self.has_refresh_token().await
+                    {
+                        refreshed_token = true;
+                        warn!(request_id = %request_id, "Request failed with 401, attempting token refresh");
+                        self.try_refresh_access_token().await?;
+                        continue;
+                    }
+
+                    if retries_left > 0 && self.is_retryable(&error) {
+                        warn!(request_id = %request_id, "Request failed with retryable error: {}. Retrying...", error);
+
+                        let delay = self.delay_for(&error, attempt);
+                        tokio::select! {
+                            _ = tokio::time::sleep(delay) => {}
+                            _ = signal.cancelled() => return Err(Error::Cancelled),
+                        }
+
+                        attempt += 1;
+                        retries_left -= 1;
+                    } else {
+                        return Err(error);
+                    }
                 }
-                Err(error) => return Err(error),
             }
         }
     }
 
-    /// Make an HTTP request with query parameters
+    /// Make an HTTP request with query parameters. Delegates to
+    /// [`BeeperDesktop::do_request_with_query_signal`] with a signal that never
+    /// trips, so current behavior is unchanged.
     pub async fn do_request_with_query<R>(
         &self,
         method: Method,
@@ -125,51 +438,136 @@ impl BeeperDesktop {
     where
         R: for<'de> Deserialize<'de>,
     {
+        self.do_request_with_query_signal(method, path, query, &AbortSignal::default()).await
+    }
+
+    /// Make an HTTP request with query parameters, cancellable via `signal`. See
+    /// [`BeeperDesktop::do_request_with_signal`] for the cancellation contract.
+    #[tracing::instrument(
+        skip(self, signal),
+        fields(method = %method, path = %path, request_id = tracing::field::Empty, attempt = tracing::field::Empty)
+    )]
+    pub async fn do_request_with_query_signal<R>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        signal: &AbortSignal,
+    ) -> Result<R>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        let request_id = generate_request_id();
+        tracing::Span::current().record("request_id", request_id.as_str());
+
+        if path != OAUTH_TOKEN_PATH {
+            self.ensure_fresh_token().await;
+        }
+
         let mut retries_left = self.config.max_retries;
-        
+        let mut attempt = 0u32;
+        let mut refreshed_token = false;
+
         loop {
-            match self.do_request_with_query_once(method.clone(), path, query).await {
+            tracing::Span::current().record("attempt", attempt);
+
+            let attempt_result = tokio::select! {
+                result = self.do_request_with_query_once(method.clone(), path, query, &request_id) => result,
+                _ = signal.cancelled() => return Err(Error::Cancelled),
+            };
+
+            match attempt_result {
                 Ok(result) => return Ok(result),
-                Err(error) if retries_left > 0 && error.is_retryable() => {
-                    warn!("Request failed with retryable error: {}. Retrying...", error);
-                    
-                    // Exponential backoff
-                    let delay = Duration::from_millis(1000 * (self.config.max_retries - retries_left + 1) as u64);
-                    tokio::time::sleep(delay).await;
-                    
-                    retries_left -= 1;
+                Err(error) => {
+                    self.log_notable_error(&request_id, &error);
+
+                    if path != OAUTH_TOKEN_PATH
+                        && !refreshed_token
+                        && matches!(error, Error::Authentication { .. })
+                        && self.has_refresh_token().await
+                    {
+                        refreshed_token = true;
+                        warn!(request_id = %request_id, "Request failed with 401, attempting token refresh");
+                        self.try_refresh_access_token().await?;
+                        continue;
+                    }
+
+                    if retries_left > 0 && self.is_retryable(&error) {
+                        warn!(request_id = %request_id, "Request failed with retryable error: {}. Retrying...", error);
+
+                        let delay = self.delay_for(&error, attempt);
+                        tokio::select! {
+                            _ = tokio::time::sleep(delay) => {}
+                            _ = signal.cancelled() => return Err(Error::Cancelled),
+                        }
+
+                        attempt += 1;
+                        retries_left -= 1;
+                    } else {
+                        return Err(error);
+                    }
                 }
-                Err(error) => return Err(error),
             }
         }
     }
 
+    /// Emit a dedicated tracing event for error categories worth flagging on
+    /// their own regardless of whether they'll be retried: rate limiting,
+    /// authentication failures, and timeouts
+    fn log_notable_error(&self, request_id: &str, error: &Error) {
+        match error {
+            Error::RateLimit { retry_after, .. } => {
+                warn!(request_id = %request_id, retry_after = ?retry_after, "Rate limited by Beeper API");
+            }
+            Error::Authentication { .. } => {
+                warn!(request_id = %request_id, "Authentication failed");
+            }
+            Error::Http(e) if e.is_timeout() => {
+                warn!(request_id = %request_id, "Request timed out");
+            }
+            _ => {}
+        }
+    }
+
     /// Make a single HTTP request without retry
-    async fn do_request_once<T, R>(&self, method: Method, path: &str, body: Option<&T>) -> Result<R>
+    async fn do_request_once<T, R>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&T>,
+        request_id: &str,
+    ) -> Result<R>
     where
         T: Serialize + ?Sized,
         R: for<'de> Deserialize<'de>,
     {
         let url = self.base_url.join(path.trim_start_matches('/'))?;
-        
+
         debug!("Making {} request to {}", method, url);
 
-        let mut request = self.http_client
-            .request(method, url)
-            .header("Authorization", format!("Bearer {}", self.config.access_token))
-            .header("User-Agent", &self.config.user_agent)
-            .header("Accept", "application/json");
+        let mut headers = self.default_headers().await;
+        headers.insert("X-Request-Id".to_string(), request_id.to_string());
+        headers.insert("Accept".to_string(), "application/json".to_string());
 
-        if let Some(body) = body {
+        let body_bytes = if let Some(body) = body {
             let json_body = serde_json::to_string(body)?;
             debug!("Request body: {}", json_body);
-            request = request
-                .header("Content-Type", "application/json")
-                .body(json_body);
-        }
+            headers.insert("Content-Type".to_string(), "application/json".to_string());
+            Some(json_body.into_bytes())
+        } else {
+            None
+        };
 
-        let response = request.send().await?;
-        self.handle_response(response).await
+        let response = self
+            .transport
+            .execute(TransportRequest {
+                method,
+                url,
+                headers,
+                body: body_bytes,
+            })
+            .await?;
+        self.handle_response(response)
     }
 
     /// Make a single HTTP request with query parameters without retry
@@ -178,13 +576,150 @@ impl BeeperDesktop {
         method: Method,
         path: &str,
         query: &[(&str, &str)],
+        request_id: &str,
     ) -> Result<R>
     where
         R: for<'de> Deserialize<'de>,
     {
+        let url = self.url_with_query(path, query)?;
+
+        debug!("Making {} request to {}", method, url);
+
+        let mut headers = self.default_headers().await;
+        headers.insert("Accept".to_string(), "application/json".to_string());
+        headers.insert("X-Request-Id".to_string(), request_id.to_string());
+
+        let response = self
+            .transport
+            .execute(TransportRequest {
+                method,
+                url,
+                headers,
+                body: None,
+            })
+            .await?;
+        self.handle_response(response)
+    }
+
+    /// Fetch raw bytes (no JSON envelope) with query parameters, used for media
+    /// download. Retries on the same retryable-error classification as
+    /// [`BeeperDesktop::do_request_with_query`], and shares its pre-emptive and
+    /// reactive token-refresh behavior so media downloads don't hard-fail on a
+    /// stale/expired token. Returns the body bytes and the reported
+    /// `Content-Type` (defaulting to `application/octet-stream`).
+    #[tracing::instrument(
+        skip(self),
+        fields(method = %method, path = %path, request_id = tracing::field::Empty, attempt = tracing::field::Empty)
+    )]
+    pub(crate) async fn do_request_raw_with_query(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+    ) -> Result<(Vec<u8>, String)> {
+        let request_id = generate_request_id();
+        tracing::Span::current().record("request_id", request_id.as_str());
+
+        self.ensure_fresh_token().await;
+
+        let mut retries_left = self.config.max_retries;
+        let mut attempt = 0u32;
+        let mut refreshed_token = false;
+
+        loop {
+            tracing::Span::current().record("attempt", attempt);
+
+            match self
+                .do_request_raw_with_query_once(method.clone(), path, query, &request_id)
+                .await
+            {
+                Ok(result) => return Ok(result),
+                Err(error) => {
+                    self.log_notable_error(&request_id, &error);
+
+                    if !refreshed_token
+                        && matches!(error, Error::Authentication { .. })
+                        && self.has_refresh_token().await
+                    {
+                        refreshed_token = true;
+                        warn!(request_id = %request_id, "Request failed with 401, attempting token refresh");
+                        self.try_refresh_access_token().await?;
+                        continue;
+                    }
+
+                    if retries_left > 0 && self.is_retryable(&error) {
+                        warn!(request_id = %request_id, "Request failed with retryable error: {}. Retrying...", error);
+
+                        let delay = self.delay_for(&error, attempt);
+                        tokio::time::sleep(delay).await;
+
+                        attempt += 1;
+                        retries_left -= 1;
+                    } else {
+                        return Err(error);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn do_request_raw_with_query_once(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        request_id: &str,
+    ) -> Result<(Vec<u8>, String)> {
+        let url = self.url_with_query(path, query)?;
+
+        debug!("Making {} request to {}", method, url);
+
+        let mut headers = self.default_headers().await;
+        headers.insert("X-Request-Id".to_string(), request_id.to_string());
+
+        let response = self
+            .transport
+            .execute(TransportRequest {
+                method,
+                url,
+                headers,
+                body: None,
+            })
+            .await?;
+
+        let status = response.status;
+        let retry_after = parse_retry_after(response.header("retry-after"));
+
+        if !status.is_success() {
+            let body = String::from_utf8_lossy(&response.body).into_owned();
+            return self.handle_error_response::<(Vec<u8>, String)>(status, &body, retry_after);
+        }
+
+        let content_type = response
+            .header("content-type")
+            .unwrap_or("application/octet-stream")
+            .to_string();
+
+        Ok((response.body, content_type))
+    }
+
+    /// Headers sent with every request: bearer auth (the current access
+    /// token, which may have been rotated by an auto-refresh since the
+    /// client was constructed) and user agent
+    async fn default_headers(&self) -> HashMap<String, String> {
+        let mut headers = HashMap::new();
+        headers.insert(
+            "Authorization".to_string(),
+            format!("Bearer {}", self.current_access_token.read().await),
+        );
+        headers.insert("User-Agent".to_string(), self.config.user_agent.clone());
+        headers
+    }
+
+    /// Join `path` onto the base URL and append `query` as query parameters
+    fn url_with_query(&self, path: &str, query: &[(&str, &str)]) -> Result<Url> {
         let mut url = self.base_url.join(path.trim_start_matches('/'))?;
-        
-        // Add query parameters
+
         if !query.is_empty() {
             let mut url_query = url.query_pairs_mut();
             for (key, value) in query {
@@ -192,28 +727,18 @@ impl BeeperDesktop {
             }
             url_query.finish();
         }
-        
-        debug!("Making {} request to {}", method, url);
 
-        let request = self.http_client
-            .request(method, url)
-            .header("Authorization", format!("Bearer {}", self.config.access_token))
-            .header("User-Agent", &self.config.user_agent)
-            .header("Accept", "application/json");
-
-        let response = request.send().await?;
-        self.handle_response(response).await
+        Ok(url)
     }
 
-
-
     /// Handle HTTP response and convert to typed result
-    async fn handle_response<R>(&self, response: Response) -> Result<R>
+    fn handle_response<R>(&self, response: TransportResponse) -> Result<R>
     where
         R: for<'de> Deserialize<'de>,
     {
-        let status = response.status();
-        let response_text = response.text().await?;
+        let status = response.status;
+        let retry_after = parse_retry_after(response.header("retry-after"));
+        let response_text = String::from_utf8_lossy(&response.body).into_owned();
 
         debug!("Response status: {}, body: {}", status, response_text);
 
@@ -224,12 +749,12 @@ impl BeeperDesktop {
                     Error::Json(e)
                 })
         } else {
-            self.handle_error_response(status, &response_text)
+            self.handle_error_response(status, &response_text, retry_after)
         }
     }
 
     /// Convert HTTP error response to typed error
-    fn handle_error_response<R>(&self, status: StatusCode, body: &str) -> Result<R> {
+    fn handle_error_response<R>(&self, status: StatusCode, body: &str, retry_after: Option<Duration>) -> Result<R> {
         let error_response: ErrorResponse = serde_json::from_str(body).unwrap_or_else(|_| {
             ErrorResponse {
                 error: Some(body.to_string()),
@@ -275,11 +800,13 @@ impl BeeperDesktop {
                 message,
                 code: error_response.code,
                 details: error_response.details,
+                retry_after,
             }),
             status if status.is_server_error() => Err(Error::InternalServer {
                 message,
                 code: error_response.code,
                 details: error_response.details,
+                retry_after,
             }),
             _ => Err(Error::Api {
                 status: status.as_u16(),
@@ -289,4 +816,20 @@ impl BeeperDesktop {
             }),
         }
     }
+}
+
+/// Parse a `Retry-After` header value, which per RFC 9110 is either a number of
+/// seconds or an HTTP-date. Returns `None` if absent, unparseable, or already in
+/// the past.
+fn parse_retry_after(header: Option<&str>) -> Option<Duration> {
+    let value = header?;
+
+    if let Ok(seconds) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let target = chrono::DateTime::parse_from_rfc2822(value.trim()).ok()?;
+    let now = chrono::Utc::now();
+    let delta = target.with_timezone(&chrono::Utc) - now;
+    delta.to_std().ok()
 }
\ No newline at end of file
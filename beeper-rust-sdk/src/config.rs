@@ -8,18 +8,38 @@ use reqwest::Client as HttpClient;
 pub struct Config {
     /// Access token for authentication
     pub access_token: String,
+    /// Refresh token used to silently obtain a new access token when the
+    /// current one expires or is rejected with a 401. Without one, a 401 is
+    /// returned as [`crate::error::Error::Authentication`] instead of being
+    /// recovered automatically.
+    pub refresh_token: Option<String>,
     /// Base URL for the API
     pub base_url: String,
     /// Request timeout
     pub timeout: Duration,
     /// Maximum number of retries
     pub max_retries: u32,
+    /// Base delay for exponential backoff between retries
+    pub base_delay: Duration,
+    /// Maximum backoff delay, regardless of attempt count
+    pub max_backoff: Duration,
+    /// HTTP status codes that should be retried when returned as `Error::Api` or
+    /// `Error::RateLimit` (429). Other typed errors keep their built-in
+    /// classification; see [`crate::error::Error::is_retryable`].
+    pub retryable_status_codes: Vec<u16>,
+    /// How often `Messages::watch`/`Chats::watch` poll the underlying `search`
+    /// endpoint for new items
+    pub watch_poll_interval: Duration,
     /// User agent string
     pub user_agent: String,
     /// HTTP client (optional)
     pub http_client: Option<HttpClient>,
 }
 
+fn default_retryable_status_codes() -> Vec<u16> {
+    vec![408, 409, 429, 500, 502, 503, 504]
+}
+
 impl Config {
     /// Create a new configuration builder
     pub fn builder() -> ConfigBuilder {
@@ -34,11 +54,18 @@ impl Config {
         let base_url = std::env::var("BEEPER_DESKTOP_BASE_URL")
             .unwrap_or_else(|_| "http://localhost:23373".to_string());
 
+        let refresh_token = std::env::var("BEEPER_REFRESH_TOKEN").ok();
+
         Ok(Self {
             access_token,
+            refresh_token,
             base_url,
             timeout: Duration::from_secs(30),
             max_retries: 2,
+            base_delay: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            retryable_status_codes: default_retryable_status_codes(),
+            watch_poll_interval: Duration::from_secs(5),
             user_agent: format!("beeper-desktop-api-rust/{}", VERSION),
             http_client: None,
         })
@@ -66,9 +93,14 @@ impl Config {
 #[derive(Debug, Default)]
 pub struct ConfigBuilder {
     access_token: Option<String>,
+    refresh_token: Option<String>,
     base_url: Option<String>,
     timeout: Option<Duration>,
     max_retries: Option<u32>,
+    base_delay: Option<Duration>,
+    max_backoff: Option<Duration>,
+    retryable_status_codes: Option<Vec<u16>>,
+    watch_poll_interval: Option<Duration>,
     user_agent: Option<String>,
     http_client: Option<HttpClient>,
 }
@@ -85,6 +117,12 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the refresh token used to recover from an expired/rejected access token
+    pub fn refresh_token(mut self, token: impl Into<String>) -> Self {
+        self.refresh_token = Some(token.into());
+        self
+    }
+
     /// Set the base URL
     pub fn base_url(mut self, url: impl Into<String>) -> Self {
         self.base_url = Some(url.into());
@@ -103,6 +141,31 @@ impl ConfigBuilder {
         self
     }
 
+    /// Set the base delay used for exponential backoff between retries
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = Some(delay);
+        self
+    }
+
+    /// Set the maximum backoff delay between retries
+    pub fn max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = Some(delay);
+        self
+    }
+
+    /// Set which HTTP status codes are treated as retryable for `Error::Api` and
+    /// `Error::RateLimit` responses
+    pub fn retryable_status_codes(mut self, codes: Vec<u16>) -> Self {
+        self.retryable_status_codes = Some(codes);
+        self
+    }
+
+    /// Set how often `Messages::watch`/`Chats::watch` poll for new items
+    pub fn watch_poll_interval(mut self, interval: Duration) -> Self {
+        self.watch_poll_interval = Some(interval);
+        self
+    }
+
     /// Set the user agent string
     pub fn user_agent(mut self, agent: impl Into<String>) -> Self {
         self.user_agent = Some(agent.into());
@@ -121,12 +184,19 @@ impl ConfigBuilder {
             access_token: self.access_token.unwrap_or_else(|| {
                 std::env::var("BEEPER_ACCESS_TOKEN").unwrap_or_default()
             }),
+            refresh_token: self.refresh_token.or_else(|| std::env::var("BEEPER_REFRESH_TOKEN").ok()),
             base_url: self.base_url.unwrap_or_else(|| {
                 std::env::var("BEEPER_DESKTOP_BASE_URL")
                     .unwrap_or_else(|_| "http://localhost:23373".to_string())
             }),
             timeout: self.timeout.unwrap_or(Duration::from_secs(30)),
             max_retries: self.max_retries.unwrap_or(2),
+            base_delay: self.base_delay.unwrap_or(Duration::from_millis(500)),
+            max_backoff: self.max_backoff.unwrap_or(Duration::from_secs(30)),
+            retryable_status_codes: self
+                .retryable_status_codes
+                .unwrap_or_else(default_retryable_status_codes),
+            watch_poll_interval: self.watch_poll_interval.unwrap_or(Duration::from_secs(5)),
             user_agent: self.user_agent.unwrap_or_else(|| {
                 format!("beeper-desktop-api-rust/{}", VERSION)
             }),
@@ -1,5 +1,6 @@
 use thiserror::Error;
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Main error type for the Beeper Desktop API SDK
 #[derive(Error, Debug)]
@@ -83,6 +84,9 @@ pub enum Error {
         message: String,
         code: Option<String>,
         details: Option<HashMap<String, String>>,
+        /// The delay the server asked us to wait, parsed from its `Retry-After`
+        /// header (seconds or HTTP-date), if it sent one
+        retry_after: Option<Duration>,
     },
 
     /// Internal server error (5xx)
@@ -91,7 +95,38 @@ pub enum Error {
         message: String,
         code: Option<String>,
         details: Option<HashMap<String, String>>,
+        /// The delay the server asked us to wait, parsed from its `Retry-After`
+        /// header (seconds or HTTP-date), if it sent one (common on 503s)
+        retry_after: Option<Duration>,
     },
+
+    /// The request was cancelled via an [`crate::abort::AbortSignal`] before it
+    /// completed
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// The current token lacks a scope a resource method requires, caught
+    /// locally by [`crate::capabilities::Capabilities::require`] instead of
+    /// round-tripping to the server for an opaque 403
+    #[error("Insufficient scope: required {required:?}, granted {granted:?}")]
+    InsufficientScope {
+        required: Vec<crate::capabilities::Scope>,
+        granted: Vec<crate::capabilities::Scope>,
+    },
+
+    /// Returned by the client's internal token refresh when no
+    /// `refresh_token` is configured. Requests do not return it: a 401 with
+    /// no `refresh_token` is returned as `Authentication`, and a failed
+    /// refresh returns the refresh request's own error
+    #[error("Token expired: {message}")]
+    TokenExpired { message: String },
+
+    /// A [`crate::gateway`] transport failure (WebSocket connect, send, or
+    /// receive error). Kept as a plain message rather than wrapping
+    /// `tokio_tungstenite::tungstenite::Error`, since that type doesn't
+    /// convert to/from `reqwest::Error` like the other transport errors here
+    #[error("Gateway error: {0}")]
+    Gateway(String),
 }
 
 impl Error {
@@ -125,6 +160,11 @@ impl Error {
             message: message.into(),
         }
     }
+
+    /// Create a gateway transport error
+    pub fn gateway(message: impl Into<String>) -> Self {
+        Self::Gateway(message.into())
+    }
 }
 
 /// Result type alias for the SDK
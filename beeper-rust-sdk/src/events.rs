@@ -0,0 +1,149 @@
+//! Push-based event subscriptions, layered on top of the [`crate::gateway`] socket
+//!
+//! `gateway()` exposes the raw, single-consumer event stream. `Events` wraps it
+//! with a friendlier, resource-flavored `Event` set and optional
+//! `account_id`/`chat_id` filtering so callers can say "tell me about new messages
+//! in this chat" instead of matching on every gateway frame themselves.
+
+use crate::client::BeeperDesktop;
+use crate::error::Result;
+use crate::gateway::Event as GatewayEvent;
+use crate::resources::shared::{Chat, Message};
+use futures::stream::{Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+/// SubscribeParams narrows a subscription to a specific account and/or chat
+#[derive(Debug, Clone, Default)]
+pub struct SubscribeParams {
+    pub account_id: Option<String>,
+    pub chat_id: Option<String>,
+}
+
+/// Event is the set of push notifications delivered by [`Events::subscribe`]
+///
+/// `ReadReceipt` and `ChatCreated` are never produced by [`Events::subscribe`]:
+/// the gateway's event frames have no counterpart for them, and chat changes
+/// arrive only as `ChatUpdated`.
+#[derive(Debug, Clone)]
+pub enum Event {
+    MessageReceived(Message),
+    MessageEdited(Message),
+    ChatCreated(Chat),
+    /// A chat changed in some way that isn't creation (rename, participant
+    /// change, etc.); distinct from `ChatCreated` so subscribers reacting to
+    /// genuinely new chats don't fire on every update
+    ChatUpdated(Chat),
+    ReactionAdded { chat_id: String, message_id: String, reaction_key: String },
+    ReminderFired { chat_id: String, message: Option<String> },
+    ReadReceipt { chat_id: String, message_id: String },
+    /// The underlying gateway socket dropped and was transparently
+    /// reconnected; see [`crate::gateway::Event::Reconnected`]. Not subject to
+    /// `account_id`/`chat_id` filtering since it isn't scoped to either.
+    Reconnected { resumed: bool },
+}
+
+/// Events handles push-based subscriptions to incoming messages and chat updates
+#[derive(Debug, Clone)]
+pub struct Events {
+    client: BeeperDesktop,
+}
+
+impl Events {
+    /// Create a new Events resource client
+    pub(crate) fn new(client: BeeperDesktop) -> Self {
+        Self { client }
+    }
+
+    /// Subscribe opens a long-lived connection to the desktop bridge and returns a
+    /// stream of events, optionally filtered by `account_id`/`chat_id`.
+    pub async fn subscribe(&self, params: SubscribeParams) -> Result<EventSubscription> {
+        let gateway_stream = self.client.gateway().connect().await?;
+        let (tx, rx) = mpsc::channel(256);
+        tokio::spawn(Self::run(gateway_stream, params, tx));
+        Ok(EventSubscription { receiver: rx })
+    }
+
+    async fn run(
+        mut gateway_stream: crate::gateway::EventStream,
+        params: SubscribeParams,
+        tx: mpsc::Sender<Result<Event>>,
+    ) {
+        while let Some(event) = gateway_stream.next().await {
+            let mapped = match event {
+                Ok(GatewayEvent::MessageCreated(message)) => {
+                    if Self::passes(&params, Some(&message.account_id), Some(&message.chat_id)) {
+                        Some(Ok(Event::MessageReceived(message)))
+                    } else {
+                        None
+                    }
+                }
+                Ok(GatewayEvent::MessageEdited(message)) => {
+                    if Self::passes(&params, Some(&message.account_id), Some(&message.chat_id)) {
+                        Some(Ok(Event::MessageEdited(message)))
+                    } else {
+                        None
+                    }
+                }
+                Ok(GatewayEvent::ReminderFired { chat_id, message }) => {
+                    if Self::passes(&params, None, Some(&chat_id)) {
+                        Some(Ok(Event::ReminderFired { chat_id, message }))
+                    } else {
+                        None
+                    }
+                }
+                Ok(GatewayEvent::ChatUpdated(chat)) => {
+                    if Self::passes(&params, Some(&chat.account_id), Some(&chat.id)) {
+                        Some(Ok(Event::ChatUpdated(chat)))
+                    } else {
+                        None
+                    }
+                }
+                Ok(GatewayEvent::MessageReacted { chat_id, message_id, reaction_key }) => {
+                    if Self::passes(&params, None, Some(&chat_id)) {
+                        Some(Ok(Event::ReactionAdded { chat_id, message_id, reaction_key }))
+                    } else {
+                        None
+                    }
+                }
+                Ok(GatewayEvent::AccountStatusChanged { .. }) => None,
+                Ok(GatewayEvent::Reconnected { resumed }) => Some(Ok(Event::Reconnected { resumed })),
+                Err(e) => Some(Err(e)),
+            };
+
+            if let Some(event) = mapped {
+                if tx.send(event).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn passes(params: &SubscribeParams, account_id: Option<&str>, chat_id: Option<&str>) -> bool {
+        if let Some(want) = &params.account_id {
+            if account_id != Some(want.as_str()) {
+                return false;
+            }
+        }
+        if let Some(want) = &params.chat_id {
+            if chat_id != Some(want.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// EventSubscription is an async [`Stream`] of filtered [`Event`]s
+pub struct EventSubscription {
+    receiver: mpsc::Receiver<Result<Event>>,
+}
+
+impl Stream for EventSubscription {
+    type Item = Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
@@ -0,0 +1,391 @@
+//! Pluggable chat-archive export backends
+//!
+//! Archiving tools write a chat's message history through an [`Exporter`]
+//! instead of hand-rolling their own formatting. Call [`Exporter::begin_chat`]
+//! once per chat, [`Exporter::write_message`] for each message in
+//! chronological order, then [`Exporter::finish_chat`] to retrieve the
+//! serialized archive. [`MarkdownExporter`], [`JsonExporter`], and
+//! [`HtmlExporter`] are provided; wrap any of them in [`EncryptingExporter`] to
+//! seal the archive at rest with a caller-supplied [`Cipher`]; this crate
+//! ships no `Cipher` implementation and derives no key from a passphrase.
+
+use crate::error::Result;
+use crate::resources::shared::{Chat, Message};
+use chrono::Utc;
+use std::io::Write;
+
+/// Writing to an in-memory `Vec<u8>` cannot fail; `Write` only returns
+/// `io::Error` to satisfy the trait's general contract for arbitrary sinks.
+const INFALLIBLE_BUFFER_WRITE: &str = "writing to an in-memory buffer cannot fail";
+
+/// Exporter writes a single chat's message history to some backing format.
+pub trait Exporter {
+    /// Start archiving `chat`, writing any header/preamble
+    fn begin_chat(&mut self, chat: &Chat) -> Result<()>;
+    /// Append a single message to the chat currently being archived
+    fn write_message(&mut self, message: &Message) -> Result<()>;
+    /// Finish the current chat's archive, returning its serialized bytes and
+    /// resetting internal state so the exporter can be reused for another chat
+    fn finish_chat(&mut self) -> Result<Vec<u8>>;
+}
+
+/// MarkdownExporter renders a chat archive as a Markdown document: a header
+/// with chat metadata and participants, followed by one `###` section per
+/// message.
+#[derive(Debug, Default)]
+pub struct MarkdownExporter {
+    buffer: Vec<u8>,
+}
+
+impl MarkdownExporter {
+    /// Create a new MarkdownExporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Exporter for MarkdownExporter {
+    fn begin_chat(&mut self, chat: &Chat) -> Result<()> {
+        writeln!(self.buffer, "# Chat Archive: {}\n", chat.title).expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(self.buffer, "- **Network:** {}", chat.network).expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(self.buffer, "- **Chat ID:** {}", chat.id).expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(self.buffer, "- **Type:** {}", chat.chat_type).expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(self.buffer, "- **Participants:** {}", chat.participants.total)
+            .expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(
+            self.buffer,
+            "- **Archived on:** {}\n",
+            Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
+        )
+        .expect(INFALLIBLE_BUFFER_WRITE);
+
+        if !chat.participants.items.is_empty() {
+            writeln!(self.buffer, "## Participants\n").expect(INFALLIBLE_BUFFER_WRITE);
+            for participant in &chat.participants.items {
+                writeln!(
+                    self.buffer,
+                    "- **{}** ({})",
+                    participant.full_name.as_deref().unwrap_or("Unknown"),
+                    participant.id
+                )
+                .expect(INFALLIBLE_BUFFER_WRITE);
+            }
+            writeln!(self.buffer).expect(INFALLIBLE_BUFFER_WRITE);
+        }
+
+        writeln!(self.buffer, "## Messages\n").expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(())
+    }
+
+    fn write_message(&mut self, message: &Message) -> Result<()> {
+        let timestamp = message.timestamp.format("%Y-%m-%d %H:%M:%S");
+        let sender = message.sender_name.as_deref().unwrap_or(&message.sender_id);
+
+        writeln!(self.buffer, "### {} - {}", sender, timestamp).expect(INFALLIBLE_BUFFER_WRITE);
+
+        if let Some(text) = &message.text {
+            writeln!(self.buffer, "{}\n", text).expect(INFALLIBLE_BUFFER_WRITE);
+        }
+
+        if let Some(attachments) = &message.attachments {
+            if !attachments.is_empty() {
+                writeln!(self.buffer, "**Attachments:**").expect(INFALLIBLE_BUFFER_WRITE);
+                for attachment in attachments {
+                    let file_name = attachment.file_name.as_deref().unwrap_or("Unknown");
+                    writeln!(
+                        self.buffer,
+                        "- {} ({})",
+                        file_name, attachment.attachment_type
+                    )
+                    .expect(INFALLIBLE_BUFFER_WRITE);
+                    if let Some(src_url) = &attachment.src_url {
+                        writeln!(self.buffer, "  - URL: {}", src_url).expect(INFALLIBLE_BUFFER_WRITE);
+                    }
+                }
+                writeln!(self.buffer).expect(INFALLIBLE_BUFFER_WRITE);
+            }
+        }
+
+        if let Some(reactions) = &message.reactions {
+            if !reactions.is_empty() {
+                write!(self.buffer, "**Reactions:** ").expect(INFALLIBLE_BUFFER_WRITE);
+                for (i, reaction) in reactions.iter().enumerate() {
+                    if i > 0 {
+                        write!(self.buffer, ", ").expect(INFALLIBLE_BUFFER_WRITE);
+                    }
+                    write!(self.buffer, "{}", reaction.reaction_key).expect(INFALLIBLE_BUFFER_WRITE);
+                }
+                writeln!(self.buffer, "\n").expect(INFALLIBLE_BUFFER_WRITE);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish_chat(&mut self) -> Result<Vec<u8>> {
+        writeln!(
+            self.buffer,
+            "\n---\n*Archive generated by Beeper Chat Archive Tool*"
+        )
+        .expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
+
+/// JsonExporter renders a chat archive as newline-delimited JSON: the first
+/// line is the chat's own record, followed by one JSON object per message,
+/// preserving the full [`Message`] structure (attachments, reactions, sort
+/// key, etc.) that the Markdown/HTML renderers only summarize.
+#[derive(Debug, Default)]
+pub struct JsonExporter {
+    buffer: Vec<u8>,
+}
+
+impl JsonExporter {
+    /// Create a new JsonExporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Exporter for JsonExporter {
+    fn begin_chat(&mut self, chat: &Chat) -> Result<()> {
+        let line = serde_json::to_string(chat)?;
+        writeln!(self.buffer, "{}", line).expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(())
+    }
+
+    fn write_message(&mut self, message: &Message) -> Result<()> {
+        let line = serde_json::to_string(message)?;
+        writeln!(self.buffer, "{}", line).expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(())
+    }
+
+    fn finish_chat(&mut self) -> Result<Vec<u8>> {
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
+
+/// HtmlExporter renders a chat archive as a minimal, self-contained HTML page.
+#[derive(Debug, Default)]
+pub struct HtmlExporter {
+    buffer: Vec<u8>,
+}
+
+impl HtmlExporter {
+    /// Create a new HtmlExporter
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Exporter for HtmlExporter {
+    fn begin_chat(&mut self, chat: &Chat) -> Result<()> {
+        writeln!(
+            self.buffer,
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title}</title></head><body>",
+            title = html_escape(&chat.title)
+        )
+        .expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(self.buffer, "<h1>{}</h1>", html_escape(&chat.title)).expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(
+            self.buffer,
+            "<p><strong>Network:</strong> {}</p>",
+            html_escape(&chat.network)
+        )
+        .expect(INFALLIBLE_BUFFER_WRITE);
+        writeln!(self.buffer, "<ul class=\"messages\">").expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(())
+    }
+
+    fn write_message(&mut self, message: &Message) -> Result<()> {
+        let sender = message.sender_name.as_deref().unwrap_or(&message.sender_id);
+        let timestamp = message.timestamp.format("%Y-%m-%d %H:%M:%S");
+        let text = message.text.as_deref().unwrap_or("");
+
+        writeln!(
+            self.buffer,
+            "<li><strong>{sender}</strong> <span class=\"timestamp\">{timestamp}</span><p>{text}</p></li>",
+            sender = html_escape(sender),
+            text = html_escape(text)
+        )
+        .expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(())
+    }
+
+    fn finish_chat(&mut self) -> Result<Vec<u8>> {
+        writeln!(self.buffer, "</ul></body></html>").expect(INFALLIBLE_BUFFER_WRITE);
+        Ok(std::mem::take(&mut self.buffer))
+    }
+}
+
+fn html_escape(input: &str) -> String {
+    input
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A pluggable sealing backend for [`EncryptingExporter`]. A real
+/// implementation should wrap a vetted AEAD construction — e.g.
+/// XChaCha20-Poly1305 in the `age` file format — deriving its key from a
+/// passphrase with a memory-hard KDF such as Argon2. This crate doesn't
+/// declare a crypto dependency, so no implementation ships here;
+/// [`EncryptingExporter`] is the integration point a caller wires one into.
+pub trait Cipher: Send + Sync {
+    /// Seal `plaintext`, returning ciphertext (plus whatever header, nonce, or
+    /// salt is needed to later open it) as a single self-contained byte string
+    fn seal(&self, plaintext: &[u8]) -> Vec<u8>;
+}
+
+/// EncryptingExporter wraps another [`Exporter`], sealing each chat's
+/// serialized archive with `cipher` before returning it from
+/// [`Exporter::finish_chat`], so exported chat histories are encrypted at
+/// rest.
+pub struct EncryptingExporter<E, C> {
+    inner: E,
+    cipher: C,
+}
+
+impl<E: Exporter, C: Cipher> EncryptingExporter<E, C> {
+    /// Wrap `inner`, sealing its serialized output with `cipher`
+    pub fn new(inner: E, cipher: C) -> Self {
+        Self { inner, cipher }
+    }
+}
+
+impl<E: Exporter, C: Cipher> Exporter for EncryptingExporter<E, C> {
+    fn begin_chat(&mut self, chat: &Chat) -> Result<()> {
+        self.inner.begin_chat(chat)
+    }
+
+    fn write_message(&mut self, message: &Message) -> Result<()> {
+        self.inner.write_message(message)
+    }
+
+    fn finish_chat(&mut self) -> Result<Vec<u8>> {
+        let plaintext = self.inner.finish_chat()?;
+        Ok(self.cipher.seal(&plaintext))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::shared::{ChatParticipants, ChatType, SortKey, User};
+
+    fn mock_chat() -> Chat {
+        Chat {
+            id: "chat_1".to_string(),
+            account_id: "account_1".to_string(),
+            network: "whatsapp".to_string(),
+            title: "<Team> & Friends".to_string(),
+            chat_type: ChatType::Group,
+            unread_count: 0,
+            participants: ChatParticipants {
+                has_more: false,
+                items: vec![User {
+                    id: "user_1".to_string(),
+                    cannot_message: None,
+                    email: None,
+                    full_name: Some("Ada Lovelace".to_string()),
+                    img_url: None,
+                    is_self: Some(false),
+                    phone_number: None,
+                    username: None,
+                }],
+                total: 1,
+            },
+            is_archived: Some(false),
+            is_muted: Some(false),
+            is_pinned: Some(false),
+            last_activity: None,
+            last_read_message_sort_key: None,
+            local_chat_id: None,
+        }
+    }
+
+    fn mock_message() -> Message {
+        Message {
+            id: "msg_1".to_string(),
+            account_id: "account_1".to_string(),
+            chat_id: "chat_1".to_string(),
+            message_id: "msg_1".to_string(),
+            sender_id: "user_1".to_string(),
+            sort_key: SortKey::Text("msg_1".to_string()),
+            timestamp: Utc::now(),
+            attachments: None,
+            is_sender: Some(false),
+            is_unread: Some(false),
+            reactions: None,
+            sender_name: Some("Ada Lovelace".to_string()),
+            text: Some("<hello> & \"world\"".to_string()),
+        }
+    }
+
+    fn export<E: Exporter>(mut exporter: E, chat: &Chat, message: &Message) -> String {
+        exporter.begin_chat(chat).unwrap();
+        exporter.write_message(message).unwrap();
+        let bytes = exporter.finish_chat().unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_markdown_exporter_renders_chat_and_message() {
+        let output = export(MarkdownExporter::new(), &mock_chat(), &mock_message());
+
+        assert!(output.contains("# Chat Archive: <Team> & Friends"));
+        assert!(output.contains("### Ada Lovelace"));
+        assert!(output.contains("<hello> & \"world\""));
+    }
+
+    #[test]
+    fn test_json_exporter_emits_one_record_per_line() {
+        let output = export(JsonExporter::new(), &mock_chat(), &mock_message());
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        let chat: Chat = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(chat.id, "chat_1");
+        let message: Message = serde_json::from_str(lines[1]).unwrap();
+        assert_eq!(message.id, "msg_1");
+    }
+
+    #[test]
+    fn test_html_exporter_escapes_chat_and_message_content() {
+        let output = export(HtmlExporter::new(), &mock_chat(), &mock_message());
+
+        assert!(output.contains("&lt;Team&gt; &amp; Friends"));
+        assert!(output.contains("&lt;hello&gt; &amp; &quot;world&quot;"));
+        assert!(!output.contains("<Team>"));
+    }
+
+    #[test]
+    fn test_html_escape_covers_all_special_characters() {
+        assert_eq!(
+            html_escape(r#"<a href="x">b & c</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;b &amp; c&lt;/a&gt;"
+        );
+    }
+
+    struct ReverseCipher;
+
+    impl Cipher for ReverseCipher {
+        fn seal(&self, plaintext: &[u8]) -> Vec<u8> {
+            plaintext.iter().rev().copied().collect()
+        }
+    }
+
+    #[test]
+    fn test_encrypting_exporter_seals_inner_output_with_cipher() {
+        let chat = mock_chat();
+        let message = mock_message();
+
+        let output = export(EncryptingExporter::new(JsonExporter::new(), ReverseCipher), &chat, &message);
+        let plain = export(JsonExporter::new(), &chat, &message);
+
+        let expected: Vec<u8> = plain.into_bytes().into_iter().rev().collect();
+        assert_eq!(output.into_bytes(), expected);
+    }
+}
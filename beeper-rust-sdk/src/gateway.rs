@@ -0,0 +1,329 @@
+//! Real-time event gateway for the Beeper Desktop API
+//!
+//! The rest of this crate is REST-only: every shared type (`Message`, `Reaction`,
+//! `Chat`) can only be observed by polling `chats().search()` or `messages().search()`.
+//! `Gateway` instead opens a persistent connection to the Beeper Desktop event
+//! endpoint and dispatches typed events as an async [`Stream`].
+
+use crate::client::BeeperDesktop;
+use crate::error::{Error, Result};
+use crate::resources::shared::{Chat, Message};
+use futures::stream::Stream;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+use tracing::{debug, warn};
+
+/// Event represents a single typed event dispatched by the gateway
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Event {
+    MessageCreated(Message),
+    MessageEdited(Message),
+    MessageReacted {
+        chat_id: String,
+        message_id: String,
+        reaction_key: String,
+    },
+    ChatUpdated(Chat),
+    ReminderFired {
+        chat_id: String,
+        message: Option<String>,
+    },
+    AccountStatusChanged {
+        account_id: String,
+        online: bool,
+    },
+    /// Synthesized locally by [`EventStream`] after it transparently
+    /// reconnects the underlying socket; never sent by the gateway itself.
+    /// `resumed` is `true` if the session picked up from `last_seq`, `false`
+    /// if the gateway forced a fresh resubscribe (e.g. after `InvalidSession`),
+    /// in which case events between the drop and reconnect may have been missed.
+    Reconnected { resumed: bool },
+}
+
+/// Raw wire frame received from the gateway socket
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+enum Frame {
+    /// Sent once on connect; carries the heartbeat interval to honor
+    Hello { heartbeat_interval_ms: u64 },
+    /// A typed event with a monotonically increasing sequence number. Boxed
+    /// since `Event` is much larger than the other variants (it inlines a
+    /// full `Message`/`Chat`), which would otherwise bloat every `Frame`.
+    Dispatch { seq: u64, event: Box<Event> },
+    /// Sent when a resume attempt fails; the caller should reconnect fresh
+    InvalidSession,
+}
+
+/// Outbound frame sent to the gateway socket
+#[derive(Debug, Serialize)]
+#[serde(tag = "op", rename_all = "camelCase")]
+enum OutboundFrame<'a> {
+    /// Sent immediately after the socket opens, mirroring the `Authorization`/
+    /// `User-Agent` headers the REST client sends on every request
+    Identify { token: &'a str, user_agent: &'a str },
+    Heartbeat,
+    Resume { token: &'a str, seq: u64 },
+}
+
+/// GatewayEvent is an alias for [`Event`], named to match the observer-style API
+/// (`GatewayObserver::on_event`) below.
+pub type GatewayEvent = Event;
+
+/// GatewayObserver receives events pushed by [`Gateway::subscribe`].
+///
+/// This is written by hand rather than with `#[async_trait]` so that
+/// `Box<dyn GatewayObserver>` stays usable without adding that dependency:
+/// the method returns a boxed future instead of being declared `async fn`.
+pub trait GatewayObserver: Send + Sync {
+    fn on_event<'a>(&'a self, event: GatewayEvent) -> Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>>;
+}
+
+/// Gateway opens and manages a persistent connection to the Beeper Desktop event endpoint
+#[derive(Debug, Clone)]
+pub struct Gateway {
+    client: BeeperDesktop,
+}
+
+impl Gateway {
+    /// Create a new Gateway bound to the given client's configuration
+    pub(crate) fn new(client: BeeperDesktop) -> Self {
+        Self { client }
+    }
+
+    /// Connect opens the gateway connection and returns a stream of dispatched events
+    pub async fn connect(&self) -> Result<EventStream> {
+        EventStream::connect(self.client.clone()).await
+    }
+
+    /// Subscribe opens the gateway connection and fans out every dispatched event to
+    /// `observer` from a background task, until the connection ends or
+    /// [`Subscription::stop`] is called. Reconnects (with resume) are handled
+    /// transparently by the underlying [`EventStream`].
+    pub async fn subscribe(&self, observer: Arc<dyn GatewayObserver>) -> Result<Subscription> {
+        use futures::stream::StreamExt;
+
+        let mut events = self.connect().await?;
+        let handle = tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                if let Ok(event) = event {
+                    observer.on_event(event).await;
+                }
+            }
+        });
+
+        Ok(Subscription { handle })
+    }
+}
+
+/// Subscription is a handle to a running [`Gateway::subscribe`] task. Dropping it
+/// stops the task from being awaited further, but does not cancel it; call
+/// [`Subscription::stop`] to cancel explicitly.
+pub struct Subscription {
+    handle: tokio::task::JoinHandle<()>,
+}
+
+impl Subscription {
+    /// Cancel the subscription's background task
+    pub fn stop(self) {
+        self.handle.abort();
+    }
+}
+
+/// EventStream is an async [`Stream`] of gateway events, with a background task
+/// handling heartbeats, sequence tracking, and resume-on-disconnect.
+pub struct EventStream {
+    receiver: mpsc::Receiver<Result<Event>>,
+    last_seq: Arc<AtomicU64>,
+}
+
+impl EventStream {
+    async fn connect(client: BeeperDesktop) -> Result<Self> {
+        let last_seq = Arc::new(AtomicU64::new(0));
+        let (tx, rx) = mpsc::channel(256);
+
+        let socket_url = client.gateway_url()?;
+        let (mut ws_stream, _) = tokio_tungstenite::connect_async(socket_url.as_str())
+            .await
+            .map_err(|e| Error::gateway(format!("failed to connect to gateway at {}: {}", socket_url, e)))?;
+        Self::send_identify(&mut ws_stream, &client).await?;
+
+        let seq_for_task = last_seq.clone();
+        tokio::spawn(Self::run(client, ws_stream, tx, seq_for_task));
+
+        Ok(Self {
+            receiver: rx,
+            last_seq,
+        })
+    }
+
+    /// The last sequence number observed from the gateway, used for resume
+    pub fn last_seq(&self) -> u64 {
+        self.last_seq.load(Ordering::SeqCst)
+    }
+
+    /// Send the identify frame, authenticating the socket with the same access
+    /// token and user agent the REST client sends on every request
+    async fn send_identify(
+        ws_stream: &mut tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+        >,
+        client: &BeeperDesktop,
+    ) -> Result<()> {
+        use futures::SinkExt;
+
+        let token = client.access_token().await;
+        let identify = OutboundFrame::Identify {
+            token: &token,
+            user_agent: client.user_agent(),
+        };
+        let frame = serde_json::to_string(&identify)?;
+        ws_stream
+            .send(WsMessage::Text(frame))
+            .await
+            .map_err(|e| Error::gateway(format!("failed to send gateway identify frame: {}", e)))
+    }
+
+    async fn run(
+        client: BeeperDesktop,
+        mut ws_stream: tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+        >,
+        tx: mpsc::Sender<Result<Event>>,
+        last_seq: Arc<AtomicU64>,
+    ) {
+        use futures::{SinkExt, StreamExt};
+
+        let mut heartbeat_interval: Option<tokio::time::Interval> = None;
+
+        loop {
+            tokio::select! {
+                frame = ws_stream.next() => {
+                    match frame {
+                        Some(Ok(WsMessage::Text(text))) => {
+                            match serde_json::from_str::<Frame>(&text) {
+                                Ok(Frame::Hello { heartbeat_interval_ms }) => {
+                                    debug!("gateway hello: heartbeat every {}ms", heartbeat_interval_ms);
+                                    heartbeat_interval = Some(tokio::time::interval(
+                                        Duration::from_millis(heartbeat_interval_ms),
+                                    ));
+                                }
+                                Ok(Frame::Dispatch { seq, event }) => {
+                                    last_seq.store(seq, Ordering::SeqCst);
+                                    if tx.send(Ok(*event)).await.is_err() {
+                                        return;
+                                    }
+                                }
+                                Ok(Frame::InvalidSession) => {
+                                    warn!("gateway rejected resume; reconnecting fresh");
+                                    last_seq.store(0, Ordering::SeqCst);
+                                    if let Ok(new_stream) = Self::reconnect(&client, 0, false).await {
+                                        ws_stream = new_stream;
+                                        if tx.send(Ok(Event::Reconnected { resumed: false })).await.is_err() {
+                                            return;
+                                        }
+                                    } else {
+                                        let _ = tx.send(Err(Error::gateway("gateway resume failed"))).await;
+                                        return;
+                                    }
+                                }
+                                Err(e) => {
+                                    let _ = tx.send(Err(Error::Json(e))).await;
+                                }
+                            }
+                        }
+                        Some(Ok(_)) => {}
+                        Some(Err(e)) => {
+                            let _ = tx.send(Err(Error::gateway(format!("gateway socket error: {}", e)))).await;
+                        }
+                        None => {
+                            let seq = last_seq.load(Ordering::SeqCst);
+                            match Self::reconnect(&client, seq, true).await {
+                                Ok(new_stream) => {
+                                    ws_stream = new_stream;
+                                    if tx.send(Ok(Event::Reconnected { resumed: true })).await.is_err() {
+                                        return;
+                                    }
+                                }
+                                Err(_) => return,
+                            }
+                        }
+                    }
+                }
+                _ = async {
+                    if let Some(interval) = heartbeat_interval.as_mut() {
+                        interval.tick().await;
+                    } else {
+                        std::future::pending::<()>().await;
+                    }
+                } => {
+                    let frame = serde_json::to_string(&OutboundFrame::Heartbeat).unwrap_or_default();
+                    if ws_stream.send(WsMessage::Text(frame)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reconnect the gateway socket. When `resume` is `true`, sends a `Resume`
+    /// frame carrying `last_seq` (the normal disconnect path); when `false`,
+    /// sends a fresh `Identify` instead, since a server-rejected resume
+    /// (`Frame::InvalidSession`) means `last_seq` is no longer honored and
+    /// resending it would risk looping on `InvalidSession` forever.
+    async fn reconnect(
+        client: &BeeperDesktop,
+        last_seq: u64,
+        resume: bool,
+    ) -> std::result::Result<
+        tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>,
+        Error,
+    > {
+        use futures::SinkExt;
+
+        let url = client.gateway_url()?;
+        let mut retries_left = client.config_max_retries();
+        let mut attempt = 0u32;
+        loop {
+            match tokio_tungstenite::connect_async(url.as_str()).await {
+                Ok((mut stream, _)) => {
+                    let token = client.access_token().await;
+                    let outbound = if resume {
+                        OutboundFrame::Resume {
+                            token: &token,
+                            seq: last_seq,
+                        }
+                    } else {
+                        OutboundFrame::Identify {
+                            token: &token,
+                            user_agent: client.user_agent(),
+                        }
+                    };
+                    let frame = serde_json::to_string(&outbound).unwrap_or_default();
+                    let _ = stream.send(WsMessage::Text(frame)).await;
+                    return Ok(stream);
+                }
+                Err(_) if retries_left > 0 => {
+                    retries_left -= 1;
+                    tokio::time::sleep(client.reconnect_backoff_delay(attempt)).await;
+                    attempt += 1;
+                }
+                Err(e) => return Err(Error::gateway(format!("gateway reconnect failed: {}", e))),
+            }
+        }
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
@@ -22,16 +22,29 @@
 //! }
 //! ```
 
+pub mod abort;
+pub mod capabilities;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod events;
+pub mod export;
+pub mod gateway;
 pub mod resources;
+pub mod rrule;
+pub mod transport;
 pub mod utils;
 pub mod version;
 
+pub use abort::AbortSignal;
+pub use capabilities::{Capabilities, Scope};
 pub use client::BeeperDesktop;
 pub use config::Config;
 pub use error::{Error, Result};
+pub use events::{EventSubscription, Events, SubscribeParams};
+pub use export::{Cipher, EncryptingExporter, Exporter, HtmlExporter, JsonExporter, MarkdownExporter};
+pub use gateway::{Event, EventStream, Gateway, GatewayEvent, GatewayObserver, Subscription};
+pub use transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
 pub use version::VERSION;
 
 // Re-export commonly used types
@@ -16,10 +16,14 @@ impl Accounts {
     }
 
     /// List retrieves all connected Beeper accounts available on this device
+    #[tracing::instrument(skip(self), fields(count = tracing::field::Empty))]
     pub async fn list(&self) -> Result<Vec<Account>> {
-        self.client
+        let accounts: Vec<Account> = self
+            .client
             .do_request(Method::GET, "/v0/get-accounts", None::<&()>)
-            .await
+            .await?;
+        tracing::Span::current().record("count", accounts.len());
+        Ok(accounts)
     }
 }
 
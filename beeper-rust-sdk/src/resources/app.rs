@@ -17,6 +17,7 @@ impl App {
     }
 
     /// DownloadAsset downloads an asset from a URL
+    #[tracing::instrument(skip(self, params))]
     pub async fn download_asset(&self, params: &AppDownloadAssetParams) -> Result<AppDownloadAssetResponse> {
         self.client
             .do_request(Method::POST, "/v0/download-asset", Some(params))
@@ -24,6 +25,7 @@ impl App {
     }
 
     /// Open opens Beeper Desktop and optionally navigates to a specific chat
+    #[tracing::instrument(skip(self, params))]
     pub async fn open(&self, params: &AppOpenParams) -> Result<AppOpenResponse> {
         self.client
             .do_request(Method::POST, "/v0/open-app", Some(params))
@@ -31,10 +33,16 @@ impl App {
     }
 
     /// Search searches for chats and messages in one call
+    #[tracing::instrument(skip(self, params), fields(chat_count = tracing::field::Empty, message_count = tracing::field::Empty))]
     pub async fn search(&self, params: &AppSearchParams) -> Result<AppSearchResponse> {
-        self.client
+        let result: AppSearchResponse = self
+            .client
             .do_request(Method::GET, "/v0/search", Some(params))
-            .await
+            .await?;
+        let span = tracing::Span::current();
+        span.record("chat_count", result.chats.len());
+        span.record("message_count", result.messages.len());
+        Ok(result)
     }
 }
 
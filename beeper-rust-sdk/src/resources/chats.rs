@@ -1,9 +1,14 @@
 use crate::client::BeeperDesktop;
-use crate::error::Result;
-use crate::resources::shared::{Chat, ChatsCursor, BaseResponse};
+use crate::error::{Error, Result};
+use crate::resources::shared::{
+    BaseResponse, Chat, ChatType, ChatsCursor, Message, MessagesCursor, PaginatedStream, SortKey, WatchStream,
+};
 use chrono::{DateTime, Utc};
+use futures::stream::{Stream, StreamExt, TryStreamExt};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tokio::sync::mpsc;
 
 /// Chats handles chat-related API operations
 #[derive(Debug, Clone)]
@@ -20,13 +25,24 @@ impl Chats {
     }
 
     /// Create creates a single or group chat on a specific account
-    pub async fn create(&self, params: &ChatCreateParams) -> Result<ChatCreateResponse> {
+    ///
+    /// Preflights the `chats.write` scope against the cached token capabilities
+    /// before issuing the request, returning [`crate::Error::InsufficientScope`]
+    /// locally instead of round-tripping to the server for a 403.
+    #[tracing::instrument(skip(self, params))]
+    pub async fn create(&self, params: &ChatCreateParams) -> Result<CreateOutcome> {
+        self.client
+            .capabilities()
+            .require(&[crate::capabilities::Scope::new(crate::capabilities::Scope::CHATS_WRITE)])
+            .await?;
+
         self.client
             .do_request(Method::POST, "/v0/create-chat", Some(params))
             .await
     }
 
     /// Retrieve gets chat details including metadata, participants, and latest message
+    #[tracing::instrument(skip(self, params))]
     pub async fn retrieve(&self, params: &ChatRetrieveParams) -> Result<Chat> {
         self.client
             .do_request(Method::GET, "/v0/get-chat", Some(params))
@@ -34,18 +50,214 @@ impl Chats {
     }
 
     /// Archive archives or unarchives a chat
+    #[tracing::instrument(skip(self, params))]
     pub async fn archive(&self, params: &ChatArchiveParams) -> Result<BaseResponse> {
         self.client
             .do_request(Method::POST, "/v0/archive-chat", Some(params))
             .await
     }
 
+    /// ReadMarker fetches the last-read position for `chat_id`, or `None` if
+    /// nothing has been read yet. A thin wrapper over [`Chats::retrieve`], since
+    /// a chat's `last_read_message_sort_key` field already carries this.
+    #[tracing::instrument(skip(self))]
+    pub async fn read_marker(&self, chat_id: &str) -> Result<Option<SortKey>> {
+        let chat = self
+            .retrieve(&ChatRetrieveParams {
+                chat_id: chat_id.to_string(),
+            })
+            .await?;
+        Ok(chat.last_read_message_sort_key)
+    }
+
+    /// SetReadMarker marks `chat_id` as read up to `up_to`, recording "where the
+    /// user left off" so it can be restored later, or combined with
+    /// [`crate::resources::messages::MessageSearchParams`] to filter a watcher
+    /// down to only unread messages.
+    #[tracing::instrument(skip(self))]
+    pub async fn set_read_marker(&self, chat_id: &str, up_to: SortKey) -> Result<BaseResponse> {
+        self.client
+            .do_request(
+                Method::POST,
+                "/v0/set-chat-read-marker",
+                Some(&SetReadMarkerParams {
+                    chat_id: chat_id.to_string(),
+                    last_read_message_sort_key: up_to,
+                }),
+            )
+            .await
+    }
+
     /// Search searches chats by title/network or participants
+    #[tracing::instrument(skip(self, params), fields(count = tracing::field::Empty))]
     pub async fn search(&self, params: &ChatSearchParams) -> Result<ChatsCursor> {
-        self.client
+        let result: ChatsCursor = self
+            .client
             .do_request(Method::GET, "/v0/search-chats", Some(params))
-            .await
+            .await?;
+        tracing::Span::current().record("count", result.items.len());
+        Ok(result)
+    }
+
+    /// SearchStream auto-paginates [`Chats::search`], yielding individual chats
+    /// across page boundaries until `has_more` is false.
+    pub fn search_stream(&self, params: &ChatSearchParams) -> impl Stream<Item = Result<Chat>> {
+        let client = self.client.clone();
+        PaginatedStream::new(params.clone(), move |params| {
+            let client = client.clone();
+            async move { client.chats().search(&params).await }
+        })
+    }
+
+    /// Like [`Chats::search_stream`], but stops after at most `max_items` chats.
+    pub fn search_stream_capped(
+        &self,
+        params: &ChatSearchParams,
+        max_items: usize,
+    ) -> impl Stream<Item = Result<Chat>> {
+        self.search_stream(params).take(max_items)
     }
+
+    /// Drains [`Chats::search_stream`] into a `Vec`, so callers don't have to pull
+    /// in `futures::TryStreamExt` themselves for the common case. Pass `max_items`
+    /// to cap how many chats are pulled before the account's entire chat list is
+    /// fetched; pass `None` to drain until `has_more` is false.
+    pub async fn try_collect_all(&self, params: &ChatSearchParams, max_items: Option<usize>) -> Result<Vec<Chat>> {
+        match max_items {
+            Some(max_items) => self.search_stream_capped(params, max_items).try_collect().await,
+            None => self.search_stream(params).try_collect().await,
+        }
+    }
+
+    /// Watch live-tails `params` for newly-appearing chats, instead of forcing
+    /// callers to re-run [`Chats::search`] in a loop. A background task polls
+    /// `search` every [`crate::Config::watch_poll_interval`] and emits each chat
+    /// id the first time it's seen; chats already present on the first poll are
+    /// treated as pre-existing and are not emitted. Drop the returned stream to
+    /// stop polling.
+    pub fn watch(&self, params: &ChatSearchParams) -> WatchStream<Chat> {
+        let client = self.client.clone();
+        let poll_interval = client.watch_poll_interval();
+        let mut params = params.clone();
+        params.cursor = None;
+
+        let (tx, rx) = mpsc::channel(256);
+        tokio::spawn(Self::watch_task(client, params, poll_interval, tx));
+        WatchStream { receiver: rx }
+    }
+
+    async fn watch_task(
+        client: BeeperDesktop,
+        params: ChatSearchParams,
+        poll_interval: std::time::Duration,
+        tx: mpsc::Sender<Result<Chat>>,
+    ) {
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut first_poll = true;
+
+        loop {
+            if tx.is_closed() {
+                return;
+            }
+            match client.chats().search(&params).await {
+                Ok(cursor) => {
+                    for chat in cursor.items {
+                        if seen.insert(chat.id.clone()) {
+                            if first_poll {
+                                continue;
+                            }
+                            if tx.send(Ok(chat)).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                    first_poll = false;
+                }
+                Err(error) => {
+                    if tx.send(Err(error)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// History fetches a single window of `chat_id`'s message backlog, reporting
+    /// an unambiguous reason when the window is empty instead of forcing callers
+    /// to guess from a bare page: whether `chat_id` doesn't exist, is forbidden,
+    /// or the backlog simply starts here. Intended for history-backfill clients
+    /// (IRC/Matrix-style `CHATHISTORY` bridges) that need to know precisely when
+    /// to stop paging backward.
+    #[tracing::instrument(skip(self, params))]
+    pub async fn history(&self, chat_id: &str, params: &HistoryParams) -> Result<HistoryResult> {
+        let mut query_params = vec![("chatIDs[0]".to_string(), chat_id.to_string())];
+
+        if let Some(limit) = params.limit {
+            query_params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(before) = params.before {
+            query_params.push(("dateBefore".to_string(), before.to_rfc3339()));
+        }
+        if let Some(after) = params.after {
+            query_params.push(("dateAfter".to_string(), after.to_rfc3339()));
+        }
+
+        let query_refs: Vec<(&str, &str)> = query_params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let result: Result<MessagesCursor> = self
+            .client
+            .do_request_with_query(Method::GET, "/v0/search-messages", &query_refs)
+            .await;
+
+        match result {
+            Ok(cursor) => {
+                let reached_start = match &cursor.pagination {
+                    Some(info) => !info.has_more && info.cursor.is_none(),
+                    None => true,
+                };
+                Ok(HistoryResult::Messages {
+                    items: cursor.items,
+                    reached_start,
+                })
+            }
+            Err(Error::NotFound { .. }) => Ok(HistoryResult::NoSuchChat),
+            Err(Error::PermissionDenied { .. }) => Ok(HistoryResult::Forbidden),
+            Err(other) => Err(other),
+        }
+    }
+}
+
+/// HistoryParams selects a single window of a chat's message backlog for
+/// [`Chats::history`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryParams {
+    pub limit: Option<i32>,
+    pub before: Option<DateTime<Utc>>,
+    pub after: Option<DateTime<Utc>>,
+}
+
+impl HistoryParams {
+    /// Create a new HistoryParams with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// HistoryResult is the outcome of a [`Chats::history`] query. Unlike a raw
+/// `Cursor<Message>` page, whose emptiness is ambiguous, it distinguishes "no
+/// such chat", "forbidden", and "reached the start of the conversation"
+/// (`has_more == false` with no cursor) from an ordinary page of results.
+#[derive(Debug, Clone)]
+pub enum HistoryResult {
+    Messages { items: Vec<Message>, reached_start: bool },
+    NoSuchChat,
+    Forbidden,
 }
 
 /// Reminders handles chat reminder operations
@@ -61,6 +273,7 @@ impl Reminders {
     }
 
     /// Create sets a reminder for a chat at a specific time
+    #[tracing::instrument(skip(self, params))]
     pub async fn create(&self, params: &ReminderCreateParams) -> Result<BaseResponse> {
         self.client
             .do_request(Method::POST, "/v0/set-chat-reminder", Some(params))
@@ -68,11 +281,83 @@ impl Reminders {
     }
 
     /// Delete clears a chat reminder
+    ///
+    /// There's only ever one active reminder per chat server-side, so this
+    /// also clears whatever series [`Reminders::create_recurring`] last set
+    /// for `params.chat_id` — there's no per-occurrence ID to target.
+    #[tracing::instrument(skip(self, params))]
     pub async fn delete(&self, params: &ReminderDeleteParams) -> Result<BaseResponse> {
         self.client
             .do_request(Method::POST, "/v0/clear-chat-reminder", Some(params))
             .await
     }
+
+    /// CreateRecurring expands `params.recurrence` (an iCalendar RRULE) into
+    /// concrete occurrence timestamps starting at `params.timestamp`, and
+    /// arms a single live reminder for the *first* occurrence.
+    ///
+    /// The server holds only one active reminder per chat (see
+    /// [`Reminders::delete`]'s doc comment), so the rest of the series can't
+    /// be pre-armed as N independent reminders — each later `create` call
+    /// would just overwrite the previous one. Callers that want the whole
+    /// series delivered must re-arm it themselves as each occurrence fires:
+    /// watch for `ReminderFired` (via [`crate::events::Events::subscribe`] or
+    /// [`crate::gateway::Gateway`]) and call [`Reminders::create`] with the
+    /// next timestamp from the returned [`ReminderSeries::occurrences`].
+    /// `max_occurrences` caps unbounded rules (no `COUNT`/`UNTIL`); pass
+    /// `None` to use [`crate::rrule::DEFAULT_MAX_OCCURRENCES`].
+    ///
+    /// Returns [`Error::Config`] if `params.recurrence` is `None` or not a
+    /// rule this SDK's expander supports — see [`crate::rrule`] for the
+    /// supported subset.
+    #[tracing::instrument(skip(self, params))]
+    pub async fn create_recurring(
+        &self,
+        params: &ReminderCreateParams,
+        max_occurrences: Option<usize>,
+    ) -> Result<ReminderSeries> {
+        let rule = params
+            .recurrence
+            .as_deref()
+            .ok_or_else(|| Error::config("create_recurring requires params.recurrence"))?;
+
+        let occurrences = crate::rrule::expand(
+            rule,
+            params.timestamp,
+            max_occurrences.unwrap_or(crate::rrule::DEFAULT_MAX_OCCURRENCES),
+        )?;
+
+        let first = *occurrences
+            .first()
+            .ok_or_else(|| Error::config("create_recurring produced no occurrences from params.recurrence"))?;
+
+        let response = self
+            .create(&ReminderCreateParams {
+                chat_id: params.chat_id.clone(),
+                timestamp: first,
+                message: params.message.clone(),
+                recurrence: None,
+            })
+            .await?;
+
+        Ok(ReminderSeries {
+            chat_id: params.chat_id.clone(),
+            occurrences,
+            response,
+        })
+    }
+}
+
+/// ReminderSeries is the result of [`Reminders::create_recurring`]: the full
+/// expanded occurrence schedule and the response from arming the first
+/// occurrence — the only one live server-side. See
+/// [`Reminders::create_recurring`]'s doc comment for how callers re-arm the
+/// rest of `occurrences` as each one fires.
+#[derive(Debug, Clone)]
+pub struct ReminderSeries {
+    pub chat_id: String,
+    pub occurrences: Vec<DateTime<Utc>>,
+    pub response: BaseResponse,
 }
 
 /// ChatCreateParams represents parameters for creating a chat
@@ -83,16 +368,77 @@ pub struct ChatCreateParams {
     pub participant_ids: Vec<String>,
     /// Type of chat (single, group)
     #[serde(rename = "type")]
-    pub chat_type: String,
+    pub chat_type: ChatType,
     pub title: Option<String>,
 }
 
-/// ChatCreateResponse represents the response from creating a chat
+/// CreateOutcome is the result of attempting to create a chat.
+///
+/// Mirrors [`crate::resources::messages::SendOutcome`]: the wire shape is
+/// `{ chat, success, error }`, but modeling it as an enum keeps a created chat
+/// from ever missing its `Chat`, and a rejected one from ever carrying one.
+/// `Chat` is boxed since it's much larger than `Rejected`'s payload, which
+/// would otherwise bloat every `CreateOutcome`.
+#[derive(Debug, Clone)]
+pub enum CreateOutcome {
+    Created(Box<Chat>),
+    Rejected { reason: Option<String> },
+}
+
+impl CreateOutcome {
+    /// Returns the created chat, or `None` if creation was rejected
+    pub fn chat(&self) -> Option<&Chat> {
+        match self {
+            CreateOutcome::Created(chat) => Some(chat),
+            CreateOutcome::Rejected { .. } => None,
+        }
+    }
+
+    /// Returns true if the chat was created
+    pub fn is_created(&self) -> bool {
+        matches!(self, CreateOutcome::Created(_))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ChatCreateResponse {
-    pub chat: Chat,
-    pub success: bool,
-    pub error: Option<String>,
+struct RawCreateResponse {
+    chat: Option<Chat>,
+    success: bool,
+    error: Option<String>,
+}
+
+impl Serialize for CreateOutcome {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let raw = match self {
+            CreateOutcome::Created(chat) => RawCreateResponse {
+                chat: Some((**chat).clone()),
+                success: true,
+                error: None,
+            },
+            CreateOutcome::Rejected { reason } => RawCreateResponse {
+                chat: None,
+                success: false,
+                error: reason.clone(),
+            },
+        };
+        raw.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CreateOutcome {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = RawCreateResponse::deserialize(deserializer)?;
+        match raw.chat {
+            Some(chat) if raw.success => Ok(CreateOutcome::Created(Box::new(chat))),
+            _ => Ok(CreateOutcome::Rejected { reason: raw.error }),
+        }
+    }
 }
 
 /// ChatRetrieveParams represents parameters for retrieving a chat
@@ -110,6 +456,14 @@ pub struct ChatArchiveParams {
     pub archived: bool,
 }
 
+/// SetReadMarkerParams represents parameters for [`Chats::set_read_marker`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SetReadMarkerParams {
+    chat_id: String,
+    last_read_message_sort_key: SortKey,
+}
+
 /// ChatSearchParams represents parameters for searching chats
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -130,6 +484,12 @@ impl ChatSearchParams {
     }
 }
 
+impl crate::resources::shared::CursorParams for ChatSearchParams {
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+}
+
 /// ReminderCreateParams represents parameters for creating a reminder
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -137,6 +497,13 @@ pub struct ReminderCreateParams {
     pub chat_id: String,
     pub timestamp: DateTime<Utc>,
     pub message: Option<String>,
+    /// An iCalendar RRULE (e.g. `FREQ=WEEKLY;BYDAY=MO,WE,FR`) for recurring
+    /// reminders. [`Reminders::create`] does not expand it: it sets a single
+    /// reminder at `timestamp` and sends this field as-is when set; pass this
+    /// to [`Reminders::create_recurring`] instead to expand it into a series. See [`crate::rrule`] for the supported
+    /// subset of RFC 5545.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<String>,
 }
 
 /// ReminderDeleteParams represents parameters for deleting a reminder
@@ -144,4 +511,199 @@ pub struct ReminderCreateParams {
 #[serde(rename_all = "camelCase")]
 pub struct ReminderDeleteParams {
     pub chat_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::shared::{ChatParticipants, User};
+    use serde_json::json;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn setup_mock_server() -> (MockServer, BeeperDesktop) {
+        let mock_server = MockServer::start().await;
+
+        let config = crate::Config::builder()
+            .access_token("test-token")
+            .base_url(mock_server.uri())
+            .timeout(std::time::Duration::from_secs(5))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        (mock_server, client)
+    }
+
+    /// Grants `chats.write` so [`Chats::create`]'s scope preflight passes
+    async fn mock_chats_write_scope(mock_server: &MockServer) {
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "iat": 0,
+                "scope": "chats.write",
+                "sub": "token-1",
+                "token_use": "access",
+                "aud": null,
+                "client_id": null,
+                "exp": null
+            })))
+            .mount(mock_server)
+            .await;
+    }
+
+    fn mock_chat(id: &str) -> Chat {
+        Chat {
+            id: id.to_string(),
+            account_id: "account_1".to_string(),
+            network: "whatsapp".to_string(),
+            title: "Test Chat".to_string(),
+            chat_type: ChatType::Single,
+            unread_count: 0,
+            participants: ChatParticipants {
+                has_more: false,
+                items: vec![User {
+                    id: "user_1".to_string(),
+                    cannot_message: None,
+                    email: None,
+                    full_name: Some("Ada Lovelace".to_string()),
+                    img_url: None,
+                    is_self: Some(false),
+                    phone_number: None,
+                    username: None,
+                }],
+                total: 1,
+            },
+            is_archived: Some(false),
+            is_muted: Some(false),
+            is_pinned: Some(false),
+            last_activity: None,
+            last_read_message_sort_key: None,
+            local_chat_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_create_returns_created_outcome() {
+        let (mock_server, client) = setup_mock_server().await;
+        mock_chats_write_scope(&mock_server).await;
+
+        let chat = mock_chat("chat_new");
+        Mock::given(method("POST"))
+            .and(path("/v0/create-chat"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "chat": chat,
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = ChatCreateParams {
+            account_id: "account_1".to_string(),
+            participant_ids: vec!["user_1".to_string()],
+            chat_type: ChatType::Single,
+            title: None,
+        };
+
+        let outcome = client.chats().create(&params).await.unwrap();
+
+        assert!(outcome.is_created());
+        assert_eq!(outcome.chat().unwrap().id, "chat_new");
+    }
+
+    #[tokio::test]
+    async fn test_chat_create_returns_rejected_outcome() {
+        let (mock_server, client) = setup_mock_server().await;
+        mock_chats_write_scope(&mock_server).await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/create-chat"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "chat": null,
+                "success": false,
+                "error": "participant not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = ChatCreateParams {
+            account_id: "account_1".to_string(),
+            participant_ids: vec!["nonexistent".to_string()],
+            chat_type: ChatType::Single,
+            title: None,
+        };
+
+        let outcome = client.chats().create(&params).await.unwrap();
+
+        assert!(!outcome.is_created());
+        assert!(outcome.chat().is_none());
+        match outcome {
+            CreateOutcome::Rejected { reason } => {
+                assert_eq!(reason.as_deref(), Some("participant not found"));
+            }
+            other => panic!("expected CreateOutcome::Rejected, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_recurring_expands_rrule_and_arms_first_occurrence() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/set-chat-reminder"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let start = DateTime::parse_from_rfc3339("2026-07-30T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let params = ReminderCreateParams {
+            chat_id: "chat123".to_string(),
+            timestamp: start,
+            message: Some("Stand-up".to_string()),
+            recurrence: Some("FREQ=DAILY;COUNT=3".to_string()),
+        };
+
+        let series = client.chats().reminders.create_recurring(&params, None).await.unwrap();
+
+        assert_eq!(series.chat_id, "chat123");
+        assert_eq!(series.occurrences.len(), 3);
+        assert_eq!(series.occurrences[0], start);
+        assert!(series.response.success);
+
+        // Only the first occurrence was armed server-side.
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_create_recurring_rejects_missing_recurrence() {
+        let (_mock_server, client) = setup_mock_server().await;
+
+        let params = ReminderCreateParams {
+            chat_id: "chat123".to_string(),
+            timestamp: Utc::now(),
+            message: None,
+            recurrence: None,
+        };
+
+        let err = client
+            .chats()
+            .reminders
+            .create_recurring(&params, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Config { .. }));
+    }
 }
\ No newline at end of file
@@ -17,15 +17,23 @@ impl Contacts {
     }
 
     /// Search searches for contacts/users
+    ///
+    /// Note: unlike `messages()`/`chats()`, `search-users` returns a single page
+    /// with no `cursor`/`has_more` in its response, so there is no `search_stream`
+    /// variant here to auto-paginate.
+    #[tracing::instrument(skip(self, params), fields(count = tracing::field::Empty))]
     pub async fn search(&self, params: &ContactSearchParams) -> Result<ContactSearchResponse> {
         let query_params = vec![
             ("accountID", params.account_id.as_str()),
             ("query", params.query.as_str()),
         ];
 
-        self.client
+        let result: ContactSearchResponse = self
+            .client
             .do_request_with_query(Method::GET, "/v0/search-users", &query_params)
-            .await
+            .await?;
+        tracing::Span::current().record("count", result.items.len());
+        Ok(result)
     }
 }
 
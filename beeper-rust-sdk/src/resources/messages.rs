@@ -1,10 +1,12 @@
 use crate::client::BeeperDesktop;
 use crate::error::Result;
-use crate::resources::shared::{MessagesCursor};
+use crate::resources::shared::{BaseResponse, Message, MessagesCursor, PaginatedStream, WatchStream};
 use crate::utils::slice_to_indexed_params;
 use chrono::{DateTime, Utc};
+use futures::stream::{Stream, StreamExt};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
 
 /// Messages handles message-related API operations
 #[derive(Debug, Clone)]
@@ -19,7 +21,62 @@ impl Messages {
     }
 
     /// Search searches messages across chats using Beeper's message index
+    #[tracing::instrument(skip(self, params), fields(count = tracing::field::Empty))]
     pub async fn search(&self, params: &MessageSearchParams) -> Result<MessagesCursor> {
+        let query_params = Self::search_query_params(params)?;
+        let query_refs: Vec<(&str, &str)> = query_params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let result: MessagesCursor = self
+            .client
+            .do_request_with_query(Method::GET, "/v0/search-messages", &query_refs)
+            .await?;
+        tracing::Span::current().record("count", result.items.len());
+        Ok(result)
+    }
+
+    /// Like [`Messages::search`], but cancellable via `signal`: if `signal` is
+    /// tripped while the request (or a retry delay) is in flight, returns
+    /// [`crate::Error::Cancelled`] instead of waiting for a response. Useful for
+    /// superseding a pending search with a newer one, e.g. as a user keeps typing.
+    #[tracing::instrument(skip(self, params, signal), fields(count = tracing::field::Empty))]
+    pub async fn search_cancellable(
+        &self,
+        params: &MessageSearchParams,
+        signal: &crate::abort::AbortSignal,
+    ) -> Result<MessagesCursor> {
+        let query_params = Self::search_query_params(params)?;
+        let query_refs: Vec<(&str, &str)> = query_params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+
+        let result: MessagesCursor = self
+            .client
+            .do_request_with_query_signal(Method::GET, "/v0/search-messages", &query_refs, signal)
+            .await?;
+        tracing::Span::current().record("count", result.items.len());
+        Ok(result)
+    }
+
+    /// Validate `params` and translate it into the query parameters expected by
+    /// `/v0/search-messages`, shared by [`Messages::search`] and
+    /// [`Messages::search_cancellable`].
+    fn search_query_params(params: &MessageSearchParams) -> Result<Vec<(String, String)>> {
+        if let (Some(after), Some(before)) = (params.date_after, params.date_before) {
+            if after > before {
+                return Err(crate::error::Error::BadRequest {
+                    message: format!(
+                        "dateAfter ({after}) must not be later than dateBefore ({before})"
+                    ),
+                    code: None,
+                    details: None,
+                });
+            }
+        }
+
         let mut query_params = Vec::new();
 
         // Handle account IDs
@@ -75,25 +132,317 @@ impl Messages {
             query_params.push(("query".to_string(), query.clone()));
         }
 
-        // Convert to the format expected by do_request_with_query
+        Ok(query_params)
+    }
+
+    /// SearchStream auto-paginates [`Messages::search`], yielding individual
+    /// messages across page boundaries until `has_more` is false. Pass the result
+    /// through `.take(max_items)` (or the `max_items` convenience below) to cap how
+    /// many messages are pulled, and `.try_collect()` to gather them into a `Vec`.
+    pub fn search_stream(&self, params: &MessageSearchParams) -> impl Stream<Item = Result<Message>> {
+        let client = self.client.clone();
+        PaginatedStream::new(params.clone(), move |params| {
+            let client = client.clone();
+            async move { client.messages().search(&params).await }
+        })
+    }
+
+    /// Like [`Messages::search_stream`], but stops after at most `max_items` messages.
+    pub fn search_stream_capped(
+        &self,
+        params: &MessageSearchParams,
+        max_items: usize,
+    ) -> impl Stream<Item = Result<Message>> {
+        self.search_stream(params).take(max_items)
+    }
+
+    /// Watch live-tails `params` for newly-arrived messages, instead of forcing
+    /// callers to re-run [`Messages::search`] in a loop. A background task polls
+    /// `search` every [`crate::Config::watch_poll_interval`], tracks the highest
+    /// `(timestamp, id)` watermark seen so far, and emits only messages strictly
+    /// newer than it — so existing history from the first poll is never replayed.
+    /// Drop the returned stream to stop polling.
+    pub fn watch(&self, params: &MessageSearchParams) -> WatchStream<Message> {
+        let client = self.client.clone();
+        let poll_interval = client.watch_poll_interval();
+        let mut params = params.clone();
+        params.cursor = None;
+
+        let (tx, rx) = mpsc::channel(256);
+        tokio::spawn(Self::watch_task(client, params, poll_interval, tx));
+        WatchStream { receiver: rx }
+    }
+
+    async fn watch_task(
+        client: BeeperDesktop,
+        params: MessageSearchParams,
+        poll_interval: std::time::Duration,
+        tx: mpsc::Sender<Result<Message>>,
+    ) {
+        let mut watermark: Option<(DateTime<Utc>, String)> = None;
+        let mut first_poll = true;
+
+        loop {
+            if tx.is_closed() {
+                return;
+            }
+            match client.messages().search(&params).await {
+                Ok(cursor) => {
+                    let mut items = cursor.items;
+                    items.sort_by(|a, b| (a.timestamp, &a.id).cmp(&(b.timestamp, &b.id)));
+
+                    for message in items {
+                        let key = (message.timestamp, message.id.clone());
+                        if let Some(mark) = &watermark {
+                            if key <= *mark {
+                                continue;
+                            }
+                        }
+                        watermark = Some(key);
+                        if first_poll {
+                            // Establish the baseline without replaying pre-existing history
+                            continue;
+                        }
+                        if tx.send(Ok(message)).await.is_err() {
+                            return;
+                        }
+                    }
+                    first_poll = false;
+                }
+                Err(error) => {
+                    if tx.send(Err(error)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Send sends a text message to a specific chat
+    #[tracing::instrument(skip(self, params))]
+    pub async fn send(&self, params: &MessageSendParams) -> Result<SendOutcome> {
+        self.client
+            .do_request(Method::POST, "/v0/send-message", Some(params))
+            .await
+    }
+
+    /// React adds an emoji reaction to a message
+    #[tracing::instrument(skip(self, params))]
+    pub async fn react(&self, params: &MessageReactionParams) -> Result<BaseResponse> {
+        self.client
+            .do_request(Method::POST, "/v0/send-reaction", Some(params))
+            .await
+    }
+
+    /// Unreact removes a previously added emoji reaction from a message
+    #[tracing::instrument(skip(self, params))]
+    pub async fn unreact(&self, params: &MessageReactionParams) -> Result<BaseResponse> {
+        self.client
+            .do_request(Method::POST, "/v0/delete-reaction", Some(params))
+            .await
+    }
+
+    /// Delete removes a message from a chat
+    #[tracing::instrument(skip(self, params))]
+    pub async fn delete(&self, params: &MessageDeleteParams) -> Result<BaseResponse> {
+        self.client
+            .do_request(Method::POST, "/v0/delete-message", Some(params))
+            .await
+    }
+
+    /// Edit replaces the text of a previously sent message
+    #[tracing::instrument(skip(self, params))]
+    pub async fn edit(&self, params: &MessageEditParams) -> Result<BaseResponse> {
+        self.client
+            .do_request(Method::POST, "/v0/edit-message", Some(params))
+            .await
+    }
+
+    /// HistoryCursor fetches a chat's message backlog, scrolling backward/forward
+    /// with raw cursor support. Prefer [`Messages::history`] for a CHATHISTORY-style
+    /// selector API; this is the lower-level primitive it (and callers needing raw
+    /// cursor control) build on.
+    #[tracing::instrument(skip(self, params), fields(count = tracing::field::Empty))]
+    pub async fn history_cursor(&self, chat_id: &str, params: &MessageHistoryParams) -> Result<MessagesCursor> {
+        let mut query_params = vec![("chatIDs[0]".to_string(), chat_id.to_string())];
+
+        if let Some(limit) = params.limit {
+            query_params.push(("limit".to_string(), limit.to_string()));
+        }
+        if let Some(cursor) = &params.cursor {
+            query_params.push(("cursor".to_string(), cursor.clone()));
+        }
+        if let Some(direction) = &params.direction {
+            query_params.push(("direction".to_string(), direction.clone()));
+        }
+
         let query_refs: Vec<(&str, &str)> = query_params
             .iter()
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect();
 
-        self.client
+        let result: MessagesCursor = self
+            .client
             .do_request_with_query(Method::GET, "/v0/search-messages", &query_refs)
-            .await
+            .await?;
+        tracing::Span::current().record("count", result.items.len());
+        Ok(result)
     }
 
-    /// Send sends a text message to a specific chat
-    pub async fn send(&self, params: &MessageSendParams) -> Result<MessageSendResponse> {
-        self.client
-            .do_request(Method::POST, "/v0/send-message", Some(params))
-            .await
+    /// HistoryPage fetches one window of `chat_id`'s backlog in `params.direction`
+    /// and reports, via [`MessageHistoryResult`], whether another page is
+    /// available rather than forcing the caller to inspect raw pagination fields.
+    /// Pass `MessageHistoryResult::Page::next_cursor` back as `params.cursor` to
+    /// keep paging in the same direction until `Complete` is returned. Items are
+    /// sorted by `sort_key` within the page regardless of the order the server
+    /// returned them in.
+    pub async fn history_page(&self, chat_id: &str, params: &MessageHistoryParams) -> Result<MessageHistoryResult> {
+        let cursor = self.history_cursor(chat_id, params).await?;
+        let mut items = cursor.items;
+        items.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+
+        match cursor.pagination {
+            Some(info) if info.has_more && info.cursor.is_some() => Ok(MessageHistoryResult::Page {
+                items,
+                next_cursor: info.cursor.unwrap(),
+            }),
+            _ => Ok(MessageHistoryResult::Complete { items }),
+        }
+    }
+
+    /// History fetches a window of a chat's message backlog using an IRC
+    /// CHATHISTORY-style selector, always returning messages in chronological
+    /// (oldest-first) order.
+    ///
+    /// - `Latest` returns the newest `limit` messages.
+    /// - `Before`/`After` return up to `limit` messages strictly older/newer than `anchor`.
+    /// - `Around` returns up to `limit / 2` messages strictly before and up to `limit / 2`
+    ///   strictly after `anchor`'s timestamp; messages at that timestamp, the anchor
+    ///   message included, are not returned.
+    /// - `Between` returns messages strictly between the two anchors, capped at `limit`.
+    ///
+    /// A message-id anchor is looked up among the chat's 200 most recent messages; an id
+    /// not among them resolves to [`crate::Error::NotFound`].
+    #[tracing::instrument(skip(self, selector))]
+    pub async fn history(
+        &self,
+        chat_id: &str,
+        selector: HistorySelector,
+        limit: i32,
+    ) -> Result<Vec<Message>> {
+        match selector {
+            HistorySelector::Latest => {
+                let mut messages = self.fetch_window(chat_id, None, None, limit).await?;
+                messages.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+                Ok(messages)
+            }
+            HistorySelector::Before(anchor) => {
+                let before = self.resolve_timestamp(chat_id, &anchor).await?;
+                let mut messages = self.fetch_window(chat_id, None, Some(before), limit).await?;
+                messages.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+                Ok(messages)
+            }
+            HistorySelector::After(anchor) => {
+                let after = self.resolve_timestamp(chat_id, &anchor).await?;
+                let mut messages = self.fetch_window(chat_id, Some(after), None, limit).await?;
+                messages.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+                Ok(messages)
+            }
+            HistorySelector::Around(anchor) => {
+                let at = self.resolve_timestamp(chat_id, &anchor).await?;
+                let half = (limit / 2).max(1);
+                let mut before = self.fetch_window(chat_id, None, Some(at), half).await?;
+                let after = self.fetch_window(chat_id, Some(at), None, half).await?;
+                before.extend(after);
+                before.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+                Ok(before)
+            }
+            HistorySelector::Between(start, end) => {
+                let (from, to) = {
+                    let a = self.resolve_timestamp(chat_id, &start).await?;
+                    let b = self.resolve_timestamp(chat_id, &end).await?;
+                    if a <= b { (a, b) } else { (b, a) }
+                };
+                let mut messages = self.fetch_window(chat_id, Some(from), Some(to), limit).await?;
+                messages.retain(|m| m.timestamp > from && m.timestamp < to);
+                messages.sort_by(|a, b| a.sort_key.cmp(&b.sort_key));
+                Ok(messages)
+            }
+        }
+    }
+
+    /// Fetch up to `limit` messages in `chat_id` strictly within `(after, before)`
+    async fn fetch_window(
+        &self,
+        chat_id: &str,
+        after: Option<DateTime<Utc>>,
+        before: Option<DateTime<Utc>>,
+        limit: i32,
+    ) -> Result<Vec<Message>> {
+        let mut params = MessageSearchParams::new();
+        params.chat_ids = vec![chat_id.to_string()];
+        params.limit = Some(limit);
+        params.date_after = after;
+        params.date_before = before;
+        let cursor = self.search(&params).await?;
+        Ok(cursor.items)
+    }
+
+    /// Resolve an anchor into a concrete timestamp, looking up the message's
+    /// recorded timestamp when the anchor names a message id.
+    async fn resolve_timestamp(&self, chat_id: &str, anchor: &HistoryAnchor) -> Result<DateTime<Utc>> {
+        match anchor {
+            HistoryAnchor::Timestamp(ts) => Ok(*ts),
+            HistoryAnchor::MessageId(message_id) => {
+                let mut params = MessageSearchParams::new();
+                params.chat_ids = vec![chat_id.to_string()];
+                params.limit = Some(200);
+                let cursor = self.search(&params).await?;
+                cursor
+                    .items
+                    .into_iter()
+                    .find(|m| &m.message_id == message_id || &m.id == message_id)
+                    .map(|m| m.timestamp)
+                    .ok_or_else(|| {
+                        crate::Error::NotFound {
+                            message: format!("message {} not found in chat {}", message_id, chat_id),
+                            code: None,
+                            details: None,
+                        }
+                    })
+            }
+        }
     }
 }
 
+/// MessageId uniquely identifies a single message for history anchoring
+pub type MessageId = String;
+
+/// HistoryAnchor is either a specific message or an RFC3339 timestamp
+#[derive(Debug, Clone)]
+pub enum HistoryAnchor {
+    MessageId(MessageId),
+    Timestamp(DateTime<Utc>),
+}
+
+/// HistorySelector selects a window of a chat's message backlog, modeled on IRC's
+/// CHATHISTORY command.
+#[derive(Debug, Clone)]
+pub enum HistorySelector {
+    /// The newest messages in the chat
+    Latest,
+    /// Messages strictly older than the anchor
+    Before(HistoryAnchor),
+    /// Messages strictly newer than the anchor
+    After(HistoryAnchor),
+    /// Messages on either side of the anchor, excluding those at its timestamp
+    Around(HistoryAnchor),
+    /// Messages strictly between two anchors, oldest to newest
+    Between(HistoryAnchor, HistoryAnchor),
+}
+
 /// MessageSearchParams represents parameters for searching messages
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MessageSearchParams {
@@ -119,6 +468,12 @@ impl MessageSearchParams {
     }
 }
 
+impl crate::resources::shared::CursorParams for MessageSearchParams {
+    fn set_cursor(&mut self, cursor: Option<String>) {
+        self.cursor = cursor;
+    }
+}
+
 /// MessageSendParams represents parameters for sending a message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -129,14 +484,128 @@ pub struct MessageSendParams {
     pub attachment: Option<String>,
 }
 
-/// MessageSendResponse represents the response from sending a message
+/// MessageReactionParams represents parameters for adding or removing a reaction
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct MessageSendResponse {
+pub struct MessageReactionParams {
+    pub chat_id: String,
     pub message_id: String,
-    pub deeplink: String,
-    pub success: bool,
-    pub error: Option<String>,
+    pub reaction_key: String,
+}
+
+/// MessageDeleteParams represents parameters for deleting a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageDeleteParams {
+    pub chat_id: String,
+    pub message_id: String,
+}
+
+/// MessageEditParams represents parameters for editing a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageEditParams {
+    pub chat_id: String,
+    pub message_id: String,
+    pub text: String,
+}
+
+/// MessageHistoryParams represents parameters for scrolling a chat's message backlog
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageHistoryParams {
+    pub limit: Option<i32>,
+    pub cursor: Option<String>,
+    pub direction: Option<String>,
+}
+
+/// MessageHistoryResult is the outcome of a [`Messages::history_page`] query:
+/// unlike a raw `MessagesCursor`, it makes "more pages available" and "reached
+/// the start/end of the chat" mutually exclusive and explicit.
+#[derive(Debug, Clone)]
+pub enum MessageHistoryResult {
+    /// This page's messages, plus the cursor to pass back in as
+    /// `MessageHistoryParams::cursor` for the next page in the same direction
+    Page { items: Vec<Message>, next_cursor: String },
+    /// No further pages in this direction; these are the last messages available
+    Complete { items: Vec<Message> },
+}
+
+/// SendOutcome is the result of attempting to send a message.
+///
+/// The bridge reports this on the wire as `{ messageID, deeplink, success, error }`,
+/// which lets a response claim both `success: true` and a populated `error`. Modeling
+/// it as an enum makes that combination unrepresentable: a sent message always carries
+/// its `message_id`/`deeplink`, and a rejected one never does.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SendOutcome {
+    Sent { message_id: String, deeplink: String },
+    Rejected { reason: Option<String> },
+}
+
+impl SendOutcome {
+    /// Returns the id of the sent message, or `None` if the send was rejected
+    pub fn message_id(&self) -> Option<&str> {
+        match self {
+            SendOutcome::Sent { message_id, .. } => Some(message_id),
+            SendOutcome::Rejected { .. } => None,
+        }
+    }
+
+    /// Returns true if the message was sent
+    pub fn is_sent(&self) -> bool {
+        matches!(self, SendOutcome::Sent { .. })
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawSendResponse {
+    #[serde(default)]
+    message_id: String,
+    #[serde(default)]
+    deeplink: String,
+    success: bool,
+    error: Option<String>,
+}
+
+impl Serialize for SendOutcome {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let raw = match self {
+            SendOutcome::Sent { message_id, deeplink } => RawSendResponse {
+                message_id: message_id.clone(),
+                deeplink: deeplink.clone(),
+                success: true,
+                error: None,
+            },
+            SendOutcome::Rejected { reason } => RawSendResponse {
+                success: false,
+                error: reason.clone(),
+                ..Default::default()
+            },
+        };
+        raw.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SendOutcome {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = RawSendResponse::deserialize(deserializer)?;
+        Ok(if raw.success {
+            SendOutcome::Sent {
+                message_id: raw.message_id,
+                deeplink: raw.deeplink,
+            }
+        } else {
+            SendOutcome::Rejected { reason: raw.error }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -198,15 +667,144 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_message_search_rejects_inverted_date_range() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        let mut params = MessageSearchParams::new();
+        params.date_after = Some(DateTime::parse_from_rfc3339("2024-06-01T00:00:00Z").unwrap().with_timezone(&Utc));
+        params.date_before = Some(DateTime::parse_from_rfc3339("2024-01-01T00:00:00Z").unwrap().with_timezone(&Utc));
+
+        let result = client.messages().search(&params).await;
+        assert!(matches!(result, Err(crate::error::Error::BadRequest { .. })));
+
+        // No request should have been made to the mock server for an invalid range.
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_message_search_retries_honor_retry_after_header() {
+        let mock_server = MockServer::start().await;
+
+        let config = Config::builder()
+            .access_token("test-token")
+            .base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .max_retries(1)
+            .base_delay(Duration::from_millis(1))
+            .max_backoff(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        // The rate-limited response is given higher priority so it answers the
+        // first request regardless of mount order (wiremock only falls back to
+        // insertion order between mocks of equal priority); it expires after a
+        // single use, so the always-on 200 serves the retry.
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [],
+                "pagination": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = MessageSearchParams::new();
+        let result = client.messages().search(&params).await;
+        assert!(result.is_ok());
+
+        // Prove the retry actually happened: one 429 attempt, one successful retry.
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_message_search_retries_honor_retry_after_on_503() {
+        let mock_server = MockServer::start().await;
+
+        let config = Config::builder()
+            .access_token("test-token")
+            .base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .max_retries(1)
+            .base_delay(Duration::from_millis(1))
+            .max_backoff(Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        // Same higher-priority layering as the 429 case above: the 503 with a
+        // `Retry-After` header answers the first request regardless of mount
+        // order, expires after a single use, then the always-on 200 is served.
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(ResponseTemplate::new(503).insert_header("retry-after", "0"))
+            .up_to_n_times(1)
+            .with_priority(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [],
+                "pagination": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = MessageSearchParams::new();
+        let result = client.messages().search(&params).await;
+        assert!(result.is_ok());
+
+        // Prove the retry actually happened: one 503 attempt, one successful retry.
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_message_search_cancellable_returns_cancelled_on_abort() {
+        use crate::abort::AbortSignal;
+
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({"items": [], "pagination": null}))
+                    .set_delay(Duration::from_secs(5)),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let signal = AbortSignal::new();
+        let signal_for_abort = signal.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            signal_for_abort.abort();
+        });
+
+        let params = MessageSearchParams::new();
+        let result = client.messages().search_cancellable(&params, &signal).await;
+
+        assert!(matches!(result, Err(crate::error::Error::Cancelled)));
+    }
+
     #[tokio::test]
     async fn test_message_send_payload() {
         let (mock_server, client) = setup_mock_server().await;
 
-        let expected_response = MessageSendResponse {
+        let expected_response = SendOutcome::Sent {
             message_id: "msg_123".to_string(),
             deeplink: "https://beeper.com/chat/123".to_string(),
-            success: true,
-            error: None,
         };
 
         Mock::given(method("POST"))
@@ -224,10 +822,250 @@ mod tests {
         };
 
         let response = client.messages().send(&send_params).await.unwrap();
-        
-        assert!(response.success);
-        assert_eq!(response.message_id, "msg_123");
-        assert_eq!(response.deeplink, "https://beeper.com/chat/123");
+
+        assert!(response.is_sent());
+        assert_eq!(response.message_id(), Some("msg_123"));
+        assert_eq!(
+            response,
+            SendOutcome::Sent {
+                message_id: "msg_123".to_string(),
+                deeplink: "https://beeper.com/chat/123".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_message_send_rejected() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/send-message"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": false,
+                "error": "recipient blocked sender"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let send_params = MessageSendParams {
+            chat_id: "chat-123".to_string(),
+            text: "hello world".to_string(),
+            reply_to_id: None,
+            attachment: None,
+        };
+
+        let response = client.messages().send(&send_params).await.unwrap();
+
+        assert!(!response.is_sent());
+        assert_eq!(
+            response,
+            SendOutcome::Rejected {
+                reason: Some("recipient blocked sender".to_string())
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_message_react_and_unreact() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/send-reaction"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/delete-reaction"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = MessageReactionParams {
+            chat_id: "chat-123".to_string(),
+            message_id: "msg-123".to_string(),
+            reaction_key: "thumbsup".to_string(),
+        };
+
+        let react_response = client.messages().react(&params).await.unwrap();
+        assert!(react_response.success);
+
+        let unreact_response = client.messages().unreact(&params).await.unwrap();
+        assert!(unreact_response.success);
+    }
+
+    #[tokio::test]
+    async fn test_message_delete_and_edit() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/delete-message"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v0/edit-message"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let delete_params = MessageDeleteParams {
+            chat_id: "chat-123".to_string(),
+            message_id: "msg-123".to_string(),
+        };
+        let delete_response = client.messages().delete(&delete_params).await.unwrap();
+        assert!(delete_response.success);
+
+        let edit_params = MessageEditParams {
+            chat_id: "chat-123".to_string(),
+            message_id: "msg-123".to_string(),
+            text: "updated text".to_string(),
+        };
+        let edit_response = client.messages().edit(&edit_params).await.unwrap();
+        assert!(edit_response.success);
+    }
+
+    #[tokio::test]
+    async fn test_message_history() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .and(query_param("chatIDs[0]", "chat-123"))
+            .and(query_param("limit", "10"))
+            .and(query_param("direction", "before"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "items": [],
+                "pagination": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = MessageHistoryParams {
+            limit: Some(10),
+            cursor: None,
+            direction: Some("before".to_string()),
+        };
+
+        let result = client.messages().history_cursor("chat-123", &params).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_message_history_latest_returns_chronological_order() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        // The server returns messages newest-first; `history` must hand back
+        // chronological (oldest-first) order regardless.
+        let mut newer = mock_message("msg_2");
+        newer.sort_key = crate::resources::shared::SortKey::Number(2);
+        let mut older = mock_message("msg_1");
+        older.sort_key = crate::resources::shared::SortKey::Number(1);
+
+        let page = MessagesCursor {
+            items: vec![newer, older],
+            pagination: None,
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .and(query_param("chatIDs[0]", "chat-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&page))
+            .mount(&mock_server)
+            .await;
+
+        let messages = client
+            .messages()
+            .history("chat-123", HistorySelector::Latest, 10)
+            .await
+            .unwrap();
+
+        let ids: Vec<String> = messages.into_iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec!["msg_1".to_string(), "msg_2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_message_history_page_reports_next_cursor_when_more_available() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        let page = MessagesCursor {
+            items: vec![mock_message("msg_1")],
+            pagination: Some(PaginationInfo {
+                cursor: Some("cursor_2".to_string()),
+                limit: Some(1),
+                direction: Some("before".to_string()),
+                has_more: true,
+            }),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .and(query_param("chatIDs[0]", "chat-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&page))
+            .mount(&mock_server)
+            .await;
+
+        let params = MessageHistoryParams {
+            limit: Some(1),
+            cursor: None,
+            direction: Some("before".to_string()),
+        };
+
+        let result = client.messages().history_page("chat-123", &params).await.unwrap();
+        match result {
+            MessageHistoryResult::Page { items, next_cursor } => {
+                assert_eq!(items.len(), 1);
+                assert_eq!(next_cursor, "cursor_2");
+            }
+            MessageHistoryResult::Complete { .. } => panic!("expected Page, got Complete"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_history_page_reports_complete_at_end_of_backlog() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        let page = MessagesCursor {
+            items: vec![mock_message("msg_1")],
+            pagination: Some(PaginationInfo {
+                cursor: None,
+                limit: Some(1),
+                direction: Some("before".to_string()),
+                has_more: false,
+            }),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .and(query_param("chatIDs[0]", "chat-123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&page))
+            .mount(&mock_server)
+            .await;
+
+        let params = MessageHistoryParams {
+            limit: Some(1),
+            cursor: None,
+            direction: Some("before".to_string()),
+        };
+
+        let result = client.messages().history_page("chat-123", &params).await.unwrap();
+        match result {
+            MessageHistoryResult::Complete { items } => assert_eq!(items.len(), 1),
+            MessageHistoryResult::Page { .. } => panic!("expected Complete, got Page"),
+        }
     }
 
     #[tokio::test]
@@ -240,7 +1078,7 @@ mod tests {
             chat_id: "chat_1".to_string(),
             message_id: "msg_1".to_string(),
             sender_id: "user_1".to_string(),
-            sort_key: json!("1234567890"),
+            sort_key: crate::resources::shared::SortKey::Text("1234567890".to_string()),
             timestamp: Utc::now(),
             attachments: None,
             is_sender: Some(false),
@@ -282,4 +1120,251 @@ mod tests {
         assert_eq!(pagination.cursor, Some("next_cursor".to_string()));
         assert!(pagination.has_more);
     }
+
+    fn mock_message(id: &str) -> Message {
+        Message {
+            id: id.to_string(),
+            account_id: "account_1".to_string(),
+            chat_id: "chat_1".to_string(),
+            message_id: id.to_string(),
+            sender_id: "user_1".to_string(),
+            sort_key: crate::resources::shared::SortKey::Text(id.to_string()),
+            timestamp: Utc::now(),
+            attachments: None,
+            is_sender: Some(false),
+            is_unread: Some(true),
+            reactions: None,
+            sender_name: Some("Test User".to_string()),
+            text: Some(format!("message {id}")),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_message_search_stream_follows_cursor_across_pages() {
+        use futures::stream::TryStreamExt;
+
+        let (mock_server, client) = setup_mock_server().await;
+
+        let page1 = MessagesCursor {
+            items: vec![mock_message("msg_1")],
+            pagination: Some(PaginationInfo {
+                cursor: Some("cursor_2".to_string()),
+                limit: Some(1),
+                direction: None,
+                has_more: true,
+            }),
+        };
+        let page2 = MessagesCursor {
+            items: vec![mock_message("msg_2")],
+            pagination: Some(PaginationInfo {
+                cursor: None,
+                limit: Some(1),
+                direction: None,
+                has_more: false,
+            }),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .and(query_param("limit", "1"))
+            .respond_with(move |req: &wiremock::Request| {
+                let on_second_page = req
+                    .url
+                    .query_pairs()
+                    .any(|(k, v)| k.as_ref() == "cursor" && v.as_ref() == "cursor_2");
+                let body = if on_second_page { &page2 } else { &page1 };
+                ResponseTemplate::new(200).set_body_json(body)
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut params = MessageSearchParams::new();
+        params.limit = Some(1);
+
+        let items: Vec<Message> = client.messages().search_stream(&params).try_collect().await.unwrap();
+        let ids: Vec<String> = items.into_iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec!["msg_1".to_string(), "msg_2".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_message_search_stream_terminates_without_cursor() {
+        use futures::stream::TryStreamExt;
+
+        let (mock_server, client) = setup_mock_server().await;
+
+        // `has_more: true` with no `cursor` must not loop forever; the stream
+        // should yield the single page and stop.
+        let page = MessagesCursor {
+            items: vec![mock_message("msg_1")],
+            pagination: Some(PaginationInfo {
+                cursor: None,
+                limit: Some(1),
+                direction: None,
+                has_more: true,
+            }),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&page))
+            .mount(&mock_server)
+            .await;
+
+        let mut params = MessageSearchParams::new();
+        params.limit = Some(1);
+
+        let items: Vec<Message> = client.messages().search_stream(&params).try_collect().await.unwrap();
+        assert_eq!(items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_message_search_stream_capped_stops_without_fetching_next_page() {
+        use futures::stream::TryStreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let (mock_server, client) = setup_mock_server().await;
+
+        // Reports `has_more: true`, so a second page would be available — but the
+        // cap is reached by the first page's single item, and the stream must not
+        // fetch it.
+        let page1 = MessagesCursor {
+            items: vec![mock_message("msg_1")],
+            pagination: Some(PaginationInfo {
+                cursor: Some("cursor_2".to_string()),
+                limit: Some(1),
+                direction: None,
+                has_more: true,
+            }),
+        };
+
+        let request_count = Arc::new(AtomicUsize::new(0));
+        let counter = request_count.clone();
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(move |_req: &wiremock::Request| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                ResponseTemplate::new(200).set_body_json(&page1)
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut params = MessageSearchParams::new();
+        params.limit = Some(1);
+
+        let items: Vec<Message> = client
+            .messages()
+            .search_stream_capped(&params, 1)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(request_count.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_message_search_stream_propagates_page_error_without_panicking() {
+        use futures::stream::TryStreamExt;
+
+        let (mock_server, client) = setup_mock_server().await;
+
+        let page1 = MessagesCursor {
+            items: vec![mock_message("msg_1")],
+            pagination: Some(PaginationInfo {
+                cursor: Some("cursor_2".to_string()),
+                limit: Some(1),
+                direction: None,
+                has_more: true,
+            }),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(move |req: &wiremock::Request| {
+                let on_second_page = req
+                    .url
+                    .query_pairs()
+                    .any(|(k, v)| k.as_ref() == "cursor" && v.as_ref() == "cursor_2");
+                if on_second_page {
+                    ResponseTemplate::new(500).set_body_json(json!({"error": "boom"}))
+                } else {
+                    ResponseTemplate::new(200).set_body_json(&page1)
+                }
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut params = MessageSearchParams::new();
+        params.limit = Some(1);
+
+        let result: Result<Vec<Message>> = client.messages().search_stream(&params).try_collect().await;
+        let err = result.expect_err("second page failure must surface as a terminal Err item");
+        assert_eq!(err.to_string().contains("boom"), true);
+    }
+
+    fn mock_message_at(id: &str, timestamp: DateTime<Utc>) -> Message {
+        let mut message = mock_message(id);
+        message.timestamp = timestamp;
+        message
+    }
+
+    #[tokio::test]
+    async fn test_message_watch_skips_preexisting_and_emits_new() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let mock_server = MockServer::start().await;
+        let config = Config::builder()
+            .access_token("test-token")
+            .base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .max_retries(0)
+            .watch_poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        // Fixed, stable timestamps: later polls must not re-report `msg_old`
+        // just because a new `Utc::now()` call would sort after the watermark.
+        let old_at = Utc::now() - chrono::Duration::seconds(60);
+        let new_at = Utc::now();
+
+        let poll_count = Arc::new(AtomicUsize::new(0));
+        let counter = poll_count.clone();
+
+        Mock::given(method("GET"))
+            .and(path("/v0/search-messages"))
+            .respond_with(move |_req: &wiremock::Request| {
+                let n = counter.fetch_add(1, Ordering::SeqCst);
+                let body = if n == 0 {
+                    MessagesCursor {
+                        items: vec![mock_message_at("msg_old", old_at)],
+                        pagination: None,
+                    }
+                } else {
+                    MessagesCursor {
+                        items: vec![
+                            mock_message_at("msg_old", old_at),
+                            mock_message_at("msg_new", new_at),
+                        ],
+                        pagination: None,
+                    }
+                };
+                ResponseTemplate::new(200).set_body_json(&body)
+            })
+            .mount(&mock_server)
+            .await;
+
+        let mut stream = client.messages().watch(&MessageSearchParams::new());
+
+        let first = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .expect("watch stream produced no items in time")
+            .expect("watch stream ended unexpectedly")
+            .unwrap();
+
+        assert_eq!(first.id, "msg_new");
+    }
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ pub mod accounts;
 pub mod app;
 pub mod chats;
 pub mod contacts;
+pub mod media;
 pub mod messages;
 pub mod token;
 
@@ -14,5 +15,6 @@ pub use accounts::*;
 pub use app::*;
 pub use chats::*;
 pub use contacts::*;
+pub use media::*;
 pub use messages::*;
 pub use token::*;
\ No newline at end of file
@@ -1,6 +1,65 @@
+use crate::error::Result;
 use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use futures::stream::Stream;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// AttachmentType is the kind of file attached to a message, tolerating
+/// unrecognized values from the server via the `Other` catch-all variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AttachmentType {
+    Unknown,
+    Img,
+    Video,
+    Audio,
+    Other(String),
+}
+
+impl AttachmentType {
+    fn as_str(&self) -> &str {
+        match self {
+            AttachmentType::Unknown => "unknown",
+            AttachmentType::Img => "img",
+            AttachmentType::Video => "video",
+            AttachmentType::Audio => "audio",
+            AttachmentType::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl std::fmt::Display for AttachmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for AttachmentType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AttachmentType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "unknown" => AttachmentType::Unknown,
+            "img" => AttachmentType::Img,
+            "video" => AttachmentType::Video,
+            "audio" => AttachmentType::Audio,
+            _ => AttachmentType::Other(s),
+        })
+    }
+}
 
 /// Attachment represents a file attachment in a message
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,7 +67,7 @@ use std::collections::HashMap;
 pub struct Attachment {
     /// Type of attachment (unknown, img, video, audio)
     #[serde(rename = "type")]
-    pub attachment_type: String,
+    pub attachment_type: AttachmentType,
     pub duration: Option<i32>,
     pub file_name: Option<String>,
     pub file_size: Option<i64>,
@@ -43,6 +102,81 @@ pub struct ErrorResponse {
     pub details: Option<HashMap<String, String>>,
 }
 
+/// SortKey is a message's ordering key, which the server represents as either a
+/// string or a number. It implements `Ord` by comparing numerically when both
+/// sides parse as integers, falling back to a string comparison otherwise, so
+/// messages can be sorted/compared directly without callers matching on the
+/// underlying representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortKey {
+    Number(i64),
+    Text(String),
+}
+
+impl SortKey {
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            SortKey::Number(n) => Some(*n),
+            SortKey::Text(s) => s.parse().ok(),
+        }
+    }
+}
+
+impl std::fmt::Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SortKey::Number(n) => write!(f, "{}", n),
+            SortKey::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self.as_i64(), other.as_i64()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => self.to_string().cmp(&other.to_string()),
+        }
+    }
+}
+
+impl Serialize for SortKey {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            SortKey::Number(n) => serializer.serialize_i64(*n),
+            SortKey::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SortKey {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Number(n) => n
+                .as_i64()
+                .map(SortKey::Number)
+                .ok_or_else(|| serde::de::Error::custom("sort key number out of i64 range")),
+            serde_json::Value::String(s) => Ok(SortKey::Text(s)),
+            other => Err(serde::de::Error::custom(format!(
+                "invalid sort key value: {}",
+                other
+            ))),
+        }
+    }
+}
+
 /// Message represents a chat message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
@@ -56,13 +190,19 @@ pub struct Message {
     #[serde(rename = "senderID")]
     pub sender_id: String,
     #[serde(rename = "sortKey")]
-    pub sort_key: serde_json::Value, // Can be string or number
+    pub sort_key: SortKey,
     pub timestamp: DateTime<Utc>,
+    /// A message with one attachment sometimes arrives with `attachments`
+    /// as a bare object instead of a one-element array
+    #[serde(default, deserialize_with = "crate::utils::deserialize_one_or_many_opt")]
     pub attachments: Option<Vec<Attachment>>,
     #[serde(rename = "isSender")]
     pub is_sender: Option<bool>,
     #[serde(rename = "isUnread")]
     pub is_unread: Option<bool>,
+    /// A message with one reaction sometimes arrives with `reactions` as a
+    /// bare object instead of a one-element array
+    #[serde(default, deserialize_with = "crate::utils::deserialize_one_or_many_opt")]
     pub reactions: Option<Vec<Reaction>>,
     #[serde(rename = "senderName")]
     pub sender_name: Option<String>,
@@ -114,14 +254,185 @@ pub struct PaginationInfo {
     pub has_more: bool,
 }
 
+/// CursorParams is implemented by search parameter types that carry a pagination
+/// cursor, so that [`PaginatedStream`] can thread the cursor from one page into the next
+/// request without each resource re-implementing the loop.
+pub trait CursorParams: Clone + Send + 'static {
+    /// Set the cursor to continue paging from
+    fn set_cursor(&mut self, cursor: Option<String>);
+}
+
+/// Fetch a single page of `Cursor<T>` given the current params
+type FetchPage<T, P> = Box<dyn Fn(P) -> BoxFuture<'static, Result<Cursor<T>>> + Send>;
+
+/// PaginatedStream yields individual items across page boundaries of a `Cursor<T>`
+/// response, transparently issuing follow-up requests as the buffer drains.
+///
+/// It holds the last-used params plus the current page's remaining items; when the
+/// buffer is empty and the previous page reported `has_more`, it fetches the next
+/// page using the carried-forward cursor. It terminates when `has_more` is false or
+/// a page comes back empty.
+pub struct PaginatedStream<T, P: CursorParams> {
+    fetch: FetchPage<T, P>,
+    params: P,
+    buffer: VecDeque<T>,
+    next_cursor: Option<String>,
+    has_more: bool,
+    done: bool,
+    pending: Option<BoxFuture<'static, Result<Cursor<T>>>>,
+}
+
+impl<T, P: CursorParams> PaginatedStream<T, P> {
+    /// Create a new paginated stream starting from `params`, using `fetch` to
+    /// retrieve each page.
+    pub fn new<F, Fut>(params: P, fetch: F) -> Self
+    where
+        F: Fn(P) -> Fut + Send + 'static,
+        Fut: std::future::Future<Output = Result<Cursor<T>>> + Send + 'static,
+    {
+        Self {
+            fetch: Box::new(move |p| Box::pin(fetch(p))),
+            params,
+            buffer: VecDeque::new(),
+            next_cursor: None,
+            has_more: true,
+            done: false,
+            pending: None,
+        }
+    }
+}
+
+impl<T: Unpin, P: CursorParams + Unpin> Stream for PaginatedStream<T, P> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if self.done || !self.has_more {
+                return Poll::Ready(None);
+            }
+
+            if self.pending.is_none() {
+                let mut params = self.params.clone();
+                params.set_cursor(self.next_cursor.take());
+                self.pending = Some((self.fetch)(params));
+            }
+
+            let fut = self.pending.as_mut().unwrap();
+            match fut.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(result) => {
+                    self.pending = None;
+                    match result {
+                        Ok(page) => {
+                            if page.items.is_empty() {
+                                self.done = true;
+                                continue;
+                            }
+                            self.buffer.extend(page.items);
+                            match page.pagination {
+                                Some(info) if info.has_more && info.cursor.is_some() => {
+                                    self.next_cursor = info.cursor;
+                                    self.has_more = true;
+                                }
+                                _ => self.has_more = false,
+                            }
+                        }
+                        Err(e) => {
+                            self.done = true;
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// WatchStream is an async [`Stream`] of items pushed by a background polling
+/// task, e.g. [`crate::resources::messages::Messages::watch`]. It is just a
+/// thin wrapper around the receiving half of the task's channel: dropping the
+/// stream drops the receiver, which makes the task's next `send` fail and lets
+/// it shut down, so no explicit cancellation handle is needed.
+pub struct WatchStream<T> {
+    pub(crate) receiver: tokio::sync::mpsc::Receiver<Result<T>>,
+}
+
+impl<T> Stream for WatchStream<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
 /// MessagesCursor is a type alias for message pagination
 pub type MessagesCursor = Cursor<Message>;
 
-/// ChatsCursor represents paginated chat results
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ChatsCursor {
-    pub items: Vec<Chat>,
-    pub pagination: Option<PaginationInfo>,
+/// ChatsCursor is a type alias for chat pagination, matching the
+/// [`MessagesCursor`] pattern so it satisfies the `Cursor<T>` bound
+/// `search_stream` helpers rely on
+pub type ChatsCursor = Cursor<Chat>;
+
+/// ChatType is whether a chat is a 1:1 conversation or a group, tolerating
+/// unrecognized values from the server via the `Other` catch-all variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatType {
+    Single,
+    Group,
+    Other(String),
+}
+
+impl ChatType {
+    fn as_str(&self) -> &str {
+        match self {
+            ChatType::Single => "single",
+            ChatType::Group => "group",
+            ChatType::Other(s) => s.as_str(),
+        }
+    }
+}
+
+impl std::fmt::Display for ChatType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ChatType {
+    fn from(s: &str) -> Self {
+        match s {
+            "single" => ChatType::Single,
+            "group" => ChatType::Group,
+            other => ChatType::Other(other.to_string()),
+        }
+    }
+}
+
+impl Serialize for ChatType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ChatType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "single" => ChatType::Single,
+            "group" => ChatType::Group,
+            _ => ChatType::Other(s),
+        })
+    }
 }
 
 /// Chat represents a chat/conversation
@@ -134,7 +445,7 @@ pub struct Chat {
     pub title: String,
     /// Type of chat (single, group)
     #[serde(rename = "type")]
-    pub chat_type: String,
+    pub chat_type: ChatType,
     #[serde(rename = "unreadCount")]
     pub unread_count: i32,
     pub participants: ChatParticipants,
@@ -147,7 +458,7 @@ pub struct Chat {
     #[serde(rename = "lastActivity")]
     pub last_activity: Option<String>,
     #[serde(rename = "lastReadMessageSortKey")]
-    pub last_read_message_sort_key: Option<serde_json::Value>, // Can be string or number
+    pub last_read_message_sort_key: Option<SortKey>,
     #[serde(rename = "localChatID")]
     pub local_chat_id: Option<String>,
 }
@@ -157,6 +468,9 @@ pub struct Chat {
 pub struct ChatParticipants {
     #[serde(rename = "hasMore")]
     pub has_more: bool,
+    /// A single-participant chat's `items` sometimes arrives as a bare
+    /// participant object instead of a one-element array
+    #[serde(deserialize_with = "crate::utils::deserialize_one_or_many")]
     pub items: Vec<User>,
     pub total: i32,
 }
@@ -1,5 +1,7 @@
 use crate::client::BeeperDesktop;
 use crate::error::Result;
+use crate::resources::shared::BaseResponse;
+use chrono::{DateTime, Utc};
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
@@ -15,10 +17,42 @@ impl Token {
         Self { client }
     }
 
-    /// Info returns information about the authenticated user/token
+    /// Info returns information about the authenticated user/token. If the
+    /// token reports an `exp`, it's cached on the client so the pre-emptive
+    /// auto-refresh layer in `BeeperDesktop::do_request` knows when to
+    /// refresh ahead of expiry rather than waiting for a 401.
+    #[tracing::instrument(skip(self))]
     pub async fn info(&self) -> Result<UserInfo> {
-        self.client
+        let info: UserInfo = self
+            .client
             .do_request(Method::GET, "/oauth/userinfo", None::<&()>)
+            .await?;
+
+        if let Some(exp) = info.exp {
+            if let Some(expiry) = DateTime::<Utc>::from_timestamp(exp, 0) {
+                self.client.set_token_expiry(Some(expiry)).await;
+            }
+        }
+
+        Ok(info)
+    }
+
+    /// Refresh exchanges `req.refresh_token` for a new access token, for
+    /// callers managing their own token lifecycle. `BeeperDesktop`'s
+    /// auto-refresh layer (on a 401, or pre-emptively near `UserInfo.exp`)
+    /// sends its own refresh request and does not go through this method.
+    #[tracing::instrument(skip(self, req))]
+    pub async fn refresh(&self, req: &RefreshRequest) -> Result<RefreshResponse> {
+        self.client
+            .do_request(Method::POST, "/oauth/token", Some(req))
+            .await
+    }
+
+    /// Revoke invalidates `req.token` at the OAuth revocation endpoint
+    #[tracing::instrument(skip(self, req))]
+    pub async fn revoke(&self, req: &RevokeRequest) -> Result<BaseResponse> {
+        self.client
+            .do_request(Method::POST, "/oauth/revoke", Some(req))
             .await
     }
 }
@@ -30,6 +64,22 @@ pub struct RevokeRequest {
     pub token_type_hint: Option<String>,
 }
 
+/// RefreshRequest represents a token refresh request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
+}
+
+/// RefreshResponse represents a new access token obtained via [`Token::refresh`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshResponse {
+    pub access_token: String,
+    /// A rotated refresh token, if the server issues one
+    pub refresh_token: Option<String>,
+    /// Seconds until `access_token` expires, if reported
+    pub expires_in: Option<i64>,
+}
+
 /// UserInfo represents information about the authenticated user/token
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserInfo {
@@ -47,4 +97,165 @@ pub struct UserInfo {
     pub client_id: Option<String>,
     /// Expiration timestamp (Unix epoch seconds)
     pub exp: Option<i64>,
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BeeperDesktop, Config};
+    use serde_json::json;
+    use std::time::Duration;
+    use wiremock::{
+        matchers::{method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    async fn setup_mock_server() -> (MockServer, BeeperDesktop) {
+        let mock_server = MockServer::start().await;
+
+        let config = Config::builder()
+            .access_token("test-token")
+            .base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        (mock_server, client)
+    }
+
+    #[tokio::test]
+    async fn test_refresh_returns_new_access_token() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "new-token",
+                "refresh_token": "new-refresh",
+                "expires_in": 3600
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let response = client
+            .token()
+            .refresh(&super::RefreshRequest {
+                refresh_token: "old-refresh".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.access_token, "new-token");
+        assert_eq!(response.refresh_token.as_deref(), Some("new-refresh"));
+        assert_eq!(response.expires_in, Some(3600));
+    }
+
+    #[tokio::test]
+    async fn test_revoke_succeeds() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/revoke"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "success": true,
+                "error": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let response = client
+            .token()
+            .revoke(&super::RevokeRequest {
+                token: "some-token".to_string(),
+                token_type_hint: Some("access_token".to_string()),
+            })
+            .await
+            .unwrap();
+
+        assert!(response.success);
+    }
+
+    #[tokio::test]
+    async fn test_request_without_refresh_token_surfaces_original_authentication_error() {
+        let (mock_server, client) = setup_mock_server().await;
+
+        // No refresh token configured: a 401 can't be recovered from, so the
+        // auto-refresh layer must not attempt a refresh (which would only ever
+        // fail with `Error::TokenExpired`, discarding this decoded error) and
+        // must not retry — it should surface the original response untouched.
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": "invalid token",
+                "code": "INVALID_TOKEN"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let err = client.token().info().await.unwrap_err();
+        match err {
+            crate::error::Error::Authentication { message, code, .. } => {
+                assert_eq!(message, "invalid token");
+                assert_eq!(code, Some("INVALID_TOKEN".to_string()));
+            }
+            other => panic!("expected Error::Authentication, got {other:?}"),
+        }
+
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_request_auto_refreshes_with_configured_refresh_token() {
+        let mock_server = MockServer::start().await;
+
+        let config = Config::builder()
+            .access_token("expired-token")
+            .refresh_token("my-refresh-token")
+            .base_url(mock_server.uri())
+            .timeout(Duration::from_secs(5))
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let client = BeeperDesktop::with_config(config).await.unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": "token expired"
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "refreshed-token",
+                "refresh_token": null,
+                "expires_in": 3600
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/oauth/userinfo"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "iat": 0,
+                "scope": "chats.write",
+                "sub": "token-1",
+                "token_use": "access",
+                "aud": null,
+                "client_id": null,
+                "exp": null
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let info = client.token().info().await.unwrap();
+        assert_eq!(info.sub, "token-1");
+    }
+}
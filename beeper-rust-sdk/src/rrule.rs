@@ -0,0 +1,341 @@
+//! A focused RFC 5545 `RRULE` expander
+//!
+//! [`Reminders::create_recurring`](crate::resources::chats::Reminders::create_recurring)
+//! needs to turn a recurrence rule like `FREQ=WEEKLY;BYDAY=MO,WE,FR;COUNT=10`
+//! into concrete timestamps; it arms a `/v0/set-chat-reminder` call for the
+//! first of them only. This module covers the common subset of RRULE actually needed
+//! for that: `FREQ` (`DAILY`, `WEEKLY`, `MONTHLY`), `INTERVAL` (default `1`),
+//! a `COUNT` or `UNTIL` termination, and `BYDAY` for weekly rules. Anything
+//! else in the RFC (`BYMONTHDAY`, `BYSETPOS`, secondly/minutely/hourly
+//! frequencies, ...) is out of scope and rejected with [`Error::Config`].
+
+use crate::error::{Error, Result};
+use chrono::{DateTime, Datelike, Duration, Timelike, Utc, Weekday};
+
+/// Safety net for rules with neither `COUNT` nor `UNTIL`: without it, a rule
+/// like `FREQ=DAILY` would expand forever. Used as the default horizon by
+/// [`crate::resources::chats::Reminders::create_recurring`] when the caller
+/// doesn't supply one.
+pub(crate) const DEFAULT_MAX_OCCURRENCES: usize = 366;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Freq {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Termination {
+    Count(usize),
+    Until(DateTime<Utc>),
+    None,
+}
+
+#[derive(Debug, Clone)]
+struct Rrule {
+    freq: Freq,
+    interval: u32,
+    termination: Termination,
+    by_day: Vec<Weekday>,
+}
+
+fn parse_weekday(token: &str) -> Result<Weekday> {
+    match token {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        other => Err(Error::config(format!("RRULE: unsupported BYDAY value {other:?}"))),
+    }
+}
+
+fn parse_rrule(rule: &str) -> Result<Rrule> {
+    let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+
+    let mut freq = None;
+    let mut interval = 1u32;
+    let mut count = None;
+    let mut until = None;
+    let mut by_day = Vec::new();
+
+    for part in rule.split(';') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let (key, value) = part
+            .split_once('=')
+            .ok_or_else(|| Error::config(format!("RRULE: malformed component {part:?}")))?;
+
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => {
+                freq = Some(match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Freq::Daily,
+                    "WEEKLY" => Freq::Weekly,
+                    "MONTHLY" => Freq::Monthly,
+                    other => {
+                        return Err(Error::config(format!(
+                            "RRULE: unsupported FREQ {other:?} (only DAILY, WEEKLY, MONTHLY are supported)"
+                        )))
+                    }
+                });
+            }
+            "INTERVAL" => {
+                interval = value
+                    .parse()
+                    .map_err(|_| Error::config(format!("RRULE: invalid INTERVAL {value:?}")))?;
+            }
+            "COUNT" => {
+                count = Some(
+                    value
+                        .parse()
+                        .map_err(|_| Error::config(format!("RRULE: invalid COUNT {value:?}")))?,
+                );
+            }
+            "UNTIL" => {
+                let parsed = DateTime::parse_from_rfc3339(value)
+                    .or_else(|_| {
+                        chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+                            .map(|naive| naive.and_utc().into())
+                    })
+                    .map_err(|_| Error::config(format!("RRULE: invalid UNTIL {value:?}")))?;
+                until = Some(parsed.with_timezone(&Utc));
+            }
+            "BYDAY" => {
+                for token in value.split(',') {
+                    by_day.push(parse_weekday(token.trim())?);
+                }
+            }
+            other => {
+                return Err(Error::config(format!(
+                    "RRULE: unsupported component {other:?}"
+                )))
+            }
+        }
+    }
+
+    let freq = freq.ok_or_else(|| Error::config("RRULE: missing required FREQ"))?;
+    if interval == 0 {
+        return Err(Error::config("RRULE: INTERVAL must be at least 1"));
+    }
+
+    let termination = match (count, until) {
+        (Some(_), Some(_)) => {
+            return Err(Error::config("RRULE: COUNT and UNTIL are mutually exclusive"))
+        }
+        (Some(count), None) => Termination::Count(count),
+        (None, Some(until)) => Termination::Until(until),
+        (None, None) => Termination::None,
+    };
+
+    Ok(Rrule {
+        freq,
+        interval,
+        termination,
+        by_day,
+    })
+}
+
+/// Expand `rule` (an iCalendar `RRULE`, with or without the `RRULE:` prefix)
+/// into concrete occurrence timestamps starting from `dtstart`.
+///
+/// `dtstart` is the first occurrence, except for a `WEEKLY` rule with `BYDAY`,
+/// whose first occurrence is the earliest listed weekday on or after
+/// `dtstart`. Expansion stops at `COUNT`
+/// occurrences, at the first candidate past `UNTIL`, or after `max_occurrences`
+/// if the rule specifies neither (so unbounded rules can't expand forever).
+/// Every generated instance preserves `dtstart`'s time-of-day and UTC offset.
+pub fn expand(rule: &str, dtstart: DateTime<Utc>, max_occurrences: usize) -> Result<Vec<DateTime<Utc>>> {
+    let rrule = parse_rrule(rule)?;
+    let cap = match rrule.termination {
+        // `max_occurrences` is a safety net for open-ended rules, not a ceiling
+        // on an explicit COUNT — silently truncating COUNT would return fewer
+        // reminders than the caller asked for without telling them.
+        Termination::Count(count) if count > max_occurrences => {
+            return Err(Error::config(format!(
+                "RRULE: COUNT={count} exceeds max_occurrences={max_occurrences}"
+            )))
+        }
+        Termination::Count(count) => count,
+        _ => max_occurrences,
+    };
+
+    if cap == 0 {
+        return Ok(Vec::new());
+    }
+
+    let exceeds_until = |candidate: &DateTime<Utc>| match rrule.termination {
+        Termination::Until(until) => *candidate > until,
+        _ => false,
+    };
+
+    let mut occurrences = Vec::new();
+
+    match rrule.freq {
+        Freq::Weekly if !rrule.by_day.is_empty() => {
+            let mut week_start = dtstart - Duration::days(dtstart.weekday().num_days_from_monday() as i64);
+            'weeks: loop {
+                let mut days: Vec<Weekday> = rrule.by_day.clone();
+                days.sort_by_key(|d| d.num_days_from_monday());
+
+                for day in &days {
+                    let candidate = week_start + Duration::days(day.num_days_from_monday() as i64);
+                    let candidate = candidate
+                        .with_hour(dtstart.hour())
+                        .and_then(|c| c.with_minute(dtstart.minute()))
+                        .and_then(|c| c.with_second(dtstart.second()))
+                        .unwrap_or(candidate);
+
+                    if candidate < dtstart {
+                        continue;
+                    }
+                    if exceeds_until(&candidate) {
+                        break 'weeks;
+                    }
+                    occurrences.push(candidate);
+                    if occurrences.len() >= cap {
+                        break 'weeks;
+                    }
+                }
+
+                week_start += Duration::weeks(rrule.interval as i64);
+            }
+        }
+        Freq::Daily | Freq::Weekly => {
+            let step = match rrule.freq {
+                Freq::Daily => Duration::days(rrule.interval as i64),
+                Freq::Weekly => Duration::weeks(rrule.interval as i64),
+                Freq::Monthly => unreachable!(),
+            };
+            let mut candidate = dtstart;
+            loop {
+                if exceeds_until(&candidate) {
+                    break;
+                }
+                occurrences.push(candidate);
+                if occurrences.len() >= cap {
+                    break;
+                }
+                candidate += step;
+            }
+        }
+        Freq::Monthly => {
+            let mut month_offset: u32 = 0;
+            loop {
+                let candidate = add_months(dtstart, month_offset)?;
+                if exceeds_until(&candidate) {
+                    break;
+                }
+                occurrences.push(candidate);
+                if occurrences.len() >= cap {
+                    break;
+                }
+                month_offset += rrule.interval;
+            }
+        }
+    }
+
+    Ok(occurrences)
+}
+
+/// Adds `months` calendar months to `dtstart`, clamping the day-of-month if
+/// the target month is shorter (e.g. Jan 31 + 1 month -> Feb 28/29)
+fn add_months(dtstart: DateTime<Utc>, months: u32) -> Result<DateTime<Utc>> {
+    let total_months = dtstart.month0() + months;
+    let year = dtstart.year() + (total_months / 12) as i32;
+    let month = total_months % 12 + 1;
+
+    let mut day = dtstart.day();
+    loop {
+        if let Some(date) = chrono::NaiveDate::from_ymd_opt(year, month, day) {
+            let naive = date.and_time(dtstart.time());
+            return Ok(naive.and_utc());
+        }
+        day -= 1;
+        if day == 0 {
+            return Err(Error::config("RRULE: failed to compute monthly occurrence"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn dt(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_daily_with_count() {
+        let start = dt(2026, 7, 30, 9, 0);
+        let result = expand("FREQ=DAILY;COUNT=3", start, 100).unwrap();
+        assert_eq!(
+            result,
+            vec![dt(2026, 7, 30, 9, 0), dt(2026, 7, 31, 9, 0), dt(2026, 8, 1, 9, 0)]
+        );
+    }
+
+    #[test]
+    fn test_weekly_byday_emits_matching_weekdays() {
+        // 2026-07-30 is a Thursday
+        let start = dt(2026, 7, 30, 9, 0);
+        let result = expand("FREQ=WEEKLY;BYDAY=MO,WE,FR;COUNT=5", start, 100).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                dt(2026, 7, 31, 9, 0), // Fri (this week, on/after dtstart)
+                dt(2026, 8, 3, 9, 0),  // Mon
+                dt(2026, 8, 5, 9, 0),  // Wed
+                dt(2026, 8, 7, 9, 0),  // Fri
+                dt(2026, 8, 10, 9, 0), // Mon
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monthly_clamps_short_months() {
+        let start = dt(2026, 1, 31, 9, 0);
+        let result = expand("FREQ=MONTHLY;COUNT=3", start, 100).unwrap();
+        assert_eq!(
+            result,
+            vec![dt(2026, 1, 31, 9, 0), dt(2026, 2, 28, 9, 0), dt(2026, 3, 31, 9, 0)]
+        );
+    }
+
+    #[test]
+    fn test_until_stops_expansion() {
+        let start = dt(2026, 7, 30, 9, 0);
+        let result = expand("FREQ=DAILY;UNTIL=2026-08-02T09:00:00Z", start, 100).unwrap();
+        assert_eq!(
+            result,
+            vec![dt(2026, 7, 30, 9, 0), dt(2026, 7, 31, 9, 0), dt(2026, 8, 1, 9, 0), dt(2026, 8, 2, 9, 0)]
+        );
+    }
+
+    #[test]
+    fn test_unbounded_rule_is_capped() {
+        let start = dt(2026, 7, 30, 9, 0);
+        let result = expand("FREQ=DAILY", start, 5).unwrap();
+        assert_eq!(result.len(), 5);
+    }
+
+    #[test]
+    fn test_unsupported_freq_is_rejected() {
+        let start = dt(2026, 7, 30, 9, 0);
+        let err = expand("FREQ=SECONDLY;COUNT=3", start, 100).unwrap_err();
+        assert!(matches!(err, Error::Config { .. }));
+    }
+
+    #[test]
+    fn test_count_exceeding_max_occurrences_is_rejected() {
+        let start = dt(2026, 7, 30, 9, 0);
+        let err = expand("FREQ=DAILY;COUNT=10", start, 5).unwrap_err();
+        assert!(matches!(err, Error::Config { .. }));
+    }
+}
@@ -1,4 +1,6 @@
-/// Utility functions for working with optional values
+//! Utility functions for working with optional values
+
+use serde::Deserialize;
 
 /// Create an optional string
 pub fn optional_string(s: impl Into<String>) -> Option<String> {
@@ -75,4 +77,128 @@ pub fn base64_encode(data: &[u8]) -> String {
 pub fn base64_decode(data: &str) -> Result<Vec<u8>, base64::DecodeError> {
     use base64::Engine;
     base64::engine::general_purpose::URL_SAFE_NO_PAD.decode(data)
+}
+
+/// Either a bare `T` or a `Vec<T>` on the wire, tried in that order
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+/// Deserialize a field that Beeper's API sometimes collapses to a bare value
+/// instead of a one-element array (or vice versa), normalizing either shape
+/// into a `Vec<T>`. Use via `#[serde(deserialize_with = "deserialize_one_or_many")]`.
+pub fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => Ok(vec![value]),
+        OneOrMany::Many(values) => Ok(values),
+    }
+}
+
+/// Like [`deserialize_one_or_many`], but for an optional field: absent or
+/// `null` deserializes to `None`. Use via
+/// `#[serde(default, deserialize_with = "deserialize_one_or_many_opt")]`.
+pub fn deserialize_one_or_many_opt<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::One(value)) => Ok(Some(vec![value])),
+        Some(OneOrMany::Many(values)) => Ok(Some(values)),
+        None => Ok(None),
+    }
+}
+
+/// Symmetric counterpart to [`deserialize_one_or_many`] for fields that are
+/// conceptually singular but may arrive as a one-element array: unwraps a
+/// bare `T` or a single-element `Vec<T>` into `T`. Rejects arrays with more
+/// than one element, since that's not representable as a single value.
+/// Use via `#[serde(deserialize_with = "deserialize_single_from_one_or_many")]`.
+pub fn deserialize_single_from_one_or_many<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => Ok(value),
+        OneOrMany::Many(mut values) => {
+            if values.len() != 1 {
+                return Err(serde::de::Error::invalid_length(
+                    values.len(),
+                    &"a single value or a one-element array",
+                ));
+            }
+            Ok(values.remove(0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Many {
+        #[serde(deserialize_with = "deserialize_one_or_many")]
+        values: Vec<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Single {
+        #[serde(deserialize_with = "deserialize_single_from_one_or_many")]
+        value: String,
+    }
+
+    #[test]
+    fn one_or_many_accepts_bare_value() {
+        let parsed: Many = serde_json::from_str(r#"{"values": "alice"}"#).unwrap();
+        assert_eq!(parsed.values, vec!["alice".to_string()]);
+    }
+
+    #[test]
+    fn one_or_many_accepts_array() {
+        let parsed: Many = serde_json::from_str(r#"{"values": ["alice", "bob"]}"#).unwrap();
+        assert_eq!(parsed.values, vec!["alice".to_string(), "bob".to_string()]);
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct ManyOpt {
+        #[serde(default, deserialize_with = "deserialize_one_or_many_opt")]
+        values: Option<Vec<String>>,
+    }
+
+    #[test]
+    fn one_or_many_opt_accepts_bare_value() {
+        let parsed: ManyOpt = serde_json::from_str(r#"{"values": "alice"}"#).unwrap();
+        assert_eq!(parsed.values, Some(vec!["alice".to_string()]));
+    }
+
+    #[test]
+    fn one_or_many_opt_accepts_null_and_missing() {
+        let from_null: ManyOpt = serde_json::from_str(r#"{"values": null}"#).unwrap();
+        assert_eq!(from_null.values, None);
+
+        let from_missing: ManyOpt = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(from_missing.values, None);
+    }
+
+    #[test]
+    fn single_from_one_or_many_unwraps_singleton_array() {
+        let parsed: Single = serde_json::from_str(r#"{"value": ["alice"]}"#).unwrap();
+        assert_eq!(parsed.value, "alice");
+    }
+
+    #[test]
+    fn single_from_one_or_many_rejects_multi_element_array() {
+        let err = serde_json::from_str::<Single>(r#"{"value": ["alice", "bob"]}"#).unwrap_err();
+        assert!(err.to_string().contains("a single value or a one-element array"));
+    }
 }
\ No newline at end of file
@@ -125,8 +125,8 @@ async fn setup_mock_client() -> (MockServer, BeeperDesktop) {
     };
     
     let response = client.messages().send(&send_params).await.unwrap();
-    assert!(response.success);
-    assert_eq!(response.message_id, "msg456");
+    assert!(response.is_sent());
+    assert_eq!(response.message_id(), Some("msg456"));
 }
 
 #[tokio::test]
@@ -391,13 +391,13 @@ async fn test_complex_search_parameters() {
     let create_params = ChatCreateParams {
         account_id: "discord_123".to_string(),
         participant_ids: vec!["user456".to_string()],
-        chat_type: "single".to_string(),
+        chat_type: beeper_desktop_api::resources::ChatType::Single,
         title: None,
     };
     
     let create_response = client.chats().create(&create_params).await.unwrap();
-    assert!(create_response.success);
-    assert_eq!(create_response.chat.id, "new_chat_789");
+    assert!(create_response.is_created());
+    assert_eq!(create_response.chat().unwrap().id, "new_chat_789");
 
     // Test chat archiving
     let archive_params = ChatArchiveParams {
@@ -413,6 +413,7 @@ async fn test_complex_search_parameters() {
         chat_id: "chat123".to_string(),
         timestamp: Utc::now(),
         message: Some("Don't forget!".to_string()),
+        recurrence: None,
     };
     
     let reminder_response = client.chats().reminders.create(&reminder_params).await.unwrap();